@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use std::{env, fmt::Write, fs, path::Path};
+
+// The level data file has one level per line so that levels can easily be added or fixed
+const LEVELS_FILE_NAME: &str = "levels.jsonl";
+
+#[derive(Deserialize)]
+struct LevelData {
+    spaces: Vec<String>,
+    positions: Vec<[u8; 2]>,
+    #[serde(default)]
+    blockers: Vec<[u8; 2]>,
+    optimal: u8,
+}
+
+fn vectors_code(positions: &[[u8; 2]]) -> String {
+    positions
+        .iter()
+        .map(|[x, y]| format!("Vector::new({x}, {y})"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn level_code(level: &LevelData) -> Result<String, String> {
+    let height = level.spaces.len();
+    let width = level.spaces.first().ok_or("no rows")?.chars().count();
+    if let Some(idx) = level.spaces.iter().position(|r| r.chars().count() != width) {
+        return Err(format!("incorrect number of columns in row {}", idx + 1));
+    }
+
+    // The characters are turned into spaces by `Space::from_char` when the crate is compiled
+    let spaces = level
+        .spaces
+        .iter()
+        .flat_map(|r| r.chars())
+        .map(|c| format!("space({c:?})"))
+        .collect::<Vec<_>>();
+
+    if let Some([x, y]) = level
+        .positions
+        .iter()
+        .chain(level.blockers.iter())
+        .find(|[x, y]| *x as usize >= width || *y as usize >= height)
+    {
+        return Err(format!("starting position ({x}, {y}) is outside the level"));
+    }
+
+    Ok(format!(
+        "    level(
+        Vector::new({width}, {height}),
+        &[{}],
+        &[{}],
+        &[{}],
+        {},
+    ),
+",
+        spaces.join(", "),
+        vectors_code(&level.positions),
+        vectors_code(&level.blockers),
+        level.optimal
+    ))
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed={LEVELS_FILE_NAME}");
+
+    let data = fs::read_to_string(LEVELS_FILE_NAME).expect("could not read the level data file");
+    let levels = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str::<LevelData>(line)
+                .map_err(|e| e.to_string())
+                .and_then(|level| Ok((level_code(&level)?, level)))
+                .unwrap_or_else(|e| panic!("{LEVELS_FILE_NAME} line {}: {e}", idx + 1))
+        })
+        .collect::<Vec<_>>();
+
+    let max_level_size = levels
+        .iter()
+        .map(|(_, l)| l.spaces.len().max(l.spaces[0].chars().count()))
+        .max()
+        .unwrap_or_default();
+    let max_num_pieces = levels
+        .iter()
+        .map(|(_, l)| l.positions.len())
+        .max()
+        .unwrap_or_default();
+    let max_optimal_moves = levels
+        .iter()
+        .map(|(_, l)| l.optimal)
+        .max()
+        .unwrap_or_default();
+
+    let mut code = format!(
+        "pub const NUM_LEVELS: usize = {};
+pub const MAX_OPTIMAL_MOVES: usize = {max_optimal_moves};
+pub const MAX_LEVEL_SIZE: usize = {max_level_size};
+pub const MAX_STRIP_SIZE: usize = MAX_LEVEL_SIZE - 2;
+pub const MAX_NUM_PIECES: usize = {max_num_pieces};
+
+pub static LEVELS: [Level<'static>; NUM_LEVELS] = [
+",
+        levels.len()
+    );
+    for (idx, (level_code, _)) in levels.iter().enumerate() {
+        writeln!(code, "    // Level {}", idx + 1).unwrap();
+        code.push_str(level_code);
+    }
+    code.push_str("];\n");
+
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("levels.rs"),
+        code,
+    )
+    .unwrap();
+}
@@ -0,0 +1,200 @@
+use crate::{
+    Piece, Space, Vector,
+    levels::{MAX_LEVEL_SIZE, MAX_OPTIMAL_MOVES},
+    owned_level::{OwnedLevel, void_extraneous_walls},
+    solver::solve,
+};
+use core::ops::RangeInclusive;
+use itertools::{Itertools, iproduct};
+use rand::{
+    Rng,
+    seq::{IndexedRandom, SliceRandom},
+};
+
+const MIN_USER_SIZE: u8 = 3;
+
+#[derive(Debug, Clone)]
+pub struct GeneratorSettings {
+    pub num_pieces: u8,
+    // Goals in addition to one for each piece, each for a random piece
+    pub num_extra_goals: u8,
+    // Goals that any piece can finish on
+    pub num_any_goals: u8,
+    // Largest playable area, not including the outer walls
+    pub max_user_size: Vector<u8>,
+    // Chance that each space in the playable area is a wall
+    pub wall_probability: f64,
+    // Only levels with an optimal number of moves in this range are kept, which is capped at the
+    // most moves a level can have
+    pub optimal_moves: RangeInclusive<u8>,
+}
+impl Default for GeneratorSettings {
+    fn default() -> Self {
+        Self {
+            num_pieces: 2,
+            num_extra_goals: 0,
+            num_any_goals: 0,
+            max_user_size: Vector::new(MAX_LEVEL_SIZE as u8 - 2, MAX_LEVEL_SIZE as u8 - 2),
+            wall_probability: 0.2,
+            optimal_moves: 8..=20,
+        }
+    }
+}
+
+// Endlessly generates random levels with an optimal number of moves in the requested range.
+//
+// Care should be taken with the settings, as an unreachable move range will never yield a level.
+pub struct Generator<R> {
+    settings: GeneratorSettings,
+    rng: R,
+}
+impl<R: Rng> Generator<R> {
+    pub fn new(mut settings: GeneratorSettings, rng: R) -> Self {
+        settings.optimal_moves = *settings.optimal_moves.start()
+            ..=(*settings.optimal_moves.end()).min(MAX_OPTIMAL_MOVES as u8);
+
+        Self { settings, rng }
+    }
+
+    // Makes a single random level, which may not be solvable, returning the rows of spaces and
+    // starting positions
+    fn random_candidate(&mut self) -> Option<(Vec<String>, Vec<Vector<u8>>)> {
+        let max_user_size = self.settings.max_user_size;
+        let user_size = Vector::new(
+            self.rng
+                .random_range(MIN_USER_SIZE..=max_user_size.x.max(MIN_USER_SIZE)),
+            self.rng
+                .random_range(MIN_USER_SIZE..=max_user_size.y.max(MIN_USER_SIZE)),
+        );
+        let size = Vector::new(user_size.x + 2, user_size.y + 2);
+
+        // Surround the playable area with walls and scatter some more within it
+        let mut spaces = iproduct!(0..size.y, 0..size.x)
+            .map(|(y, x)| {
+                if x == 0
+                    || y == 0
+                    || x == size.x - 1
+                    || y == size.y - 1
+                    || self.rng.random_bool(self.settings.wall_probability)
+                {
+                    Space::Wall
+                } else {
+                    Space::Free
+                }
+            })
+            .collect_vec();
+
+        let mut free_positions = iproduct!(0..size.y, 0..size.x)
+            .map(|(y, x)| Vector::new(x, y))
+            .filter(|p| spaces[index(size, *p)] == Space::Free)
+            .collect_vec();
+        let num_pieces = self.settings.num_pieces as usize;
+        let num_goals = num_pieces
+            + self.settings.num_extra_goals as usize
+            + self.settings.num_any_goals as usize;
+        if free_positions.len() < num_goals + num_pieces {
+            return None;
+        }
+
+        // Place the goals, making sure that every piece has at least one
+        free_positions.shuffle(&mut self.rng);
+        let pieces = Piece::colored().take(num_pieces).collect_vec();
+        let goals = pieces
+            .iter()
+            .copied()
+            .map(Space::Goal)
+            .chain(
+                (0..self.settings.num_extra_goals)
+                    .map(|_| Space::Goal(*pieces.choose(&mut self.rng).unwrap())),
+            )
+            .chain((0..self.settings.num_any_goals).map(|_| Space::AnyGoal))
+            .collect_vec();
+        for (goal, position) in goals.into_iter().zip(&free_positions) {
+            spaces[index(size, *position)] = goal;
+        }
+
+        // Pieces can start anywhere, including on a goal
+        free_positions.shuffle(&mut self.rng);
+        let starting_positions = free_positions[..num_pieces].to_vec();
+
+        void_extraneous_walls(size, &mut spaces);
+        let rows = spaces
+            .chunks(size.x as usize)
+            .map(|row| row.iter().map(|s| char::from(*s)).collect())
+            .collect();
+
+        Some((rows, starting_positions))
+    }
+
+    // Makes a single attempt at generating a level, returning it only if it meets the settings
+    pub fn attempt(&mut self) -> Option<OwnedLevel> {
+        let (rows, starting_positions) = self.random_candidate()?;
+        let candidate = OwnedLevel::new(&rows, starting_positions.clone(), Vec::new(), 0).ok()?;
+        let optimal_moves = solve(&candidate.level())?.len();
+
+        if optimal_moves > u8::MAX as usize
+            || !self.settings.optimal_moves.contains(&(optimal_moves as u8))
+        {
+            return None;
+        }
+        OwnedLevel::new(&rows, starting_positions, Vec::new(), optimal_moves as u8).ok()
+    }
+}
+impl<R: Rng> Iterator for Generator<R> {
+    type Item = OwnedLevel;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(level) = self.attempt() {
+                break Some(level);
+            }
+        }
+    }
+}
+
+#[inline]
+fn index(size: Vector<u8>, position: Vector<u8>) -> usize {
+    size.x as usize * position.y as usize + position.x as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn generate() {
+        let settings = GeneratorSettings {
+            num_pieces: 3,
+            optimal_moves: 10..=15,
+            ..Default::default()
+        };
+
+        for generated in Generator::new(settings, StdRng::seed_from_u64(0)).take(5) {
+            let level = generated.level();
+
+            assert_eq!(level.num_pieces(), 3);
+            assert!(
+                level.size.x as usize <= MAX_LEVEL_SIZE && level.size.y as usize <= MAX_LEVEL_SIZE
+            );
+            assert!((10..=15).contains(&level.optimal_moves));
+            assert_eq!(solve(&level).unwrap().len(), level.optimal_moves as usize);
+        }
+
+        let settings = GeneratorSettings {
+            num_extra_goals: 2,
+            num_any_goals: 1,
+            optimal_moves: 5..=10,
+            ..Default::default()
+        };
+        for generated in Generator::new(settings, StdRng::seed_from_u64(0)).take(3) {
+            let spaces = generated.level().spaces;
+            let goals = spaces
+                .iter()
+                .filter(|s| matches!(s, Space::Goal(_)))
+                .count();
+            assert_eq!(goals, 4);
+            assert!(spaces.contains(&Space::AnyGoal));
+        }
+    }
+}
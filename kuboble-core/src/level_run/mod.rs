@@ -1,16 +1,26 @@
 use crate::{
+    Level, LevelRating, Piece, PieceMap, Rules, Space, Vector,
     level_select::{LevelInfo, LevelStatus},
     levels::MAX_STRIP_SIZE,
-    Level, LevelRating, Piece, PieceMap, Space, Vector,
+};
+#[cfg(not(feature = "std"))]
+use crate::{
+    levels::MAX_OPTIMAL_MOVES,
+    solver::bounded::{BoundedSolution, solve_bounded},
 };
 use arrayvec::{ArrayString, ArrayVec};
 use core::{fmt::Write, mem::variant_count, ops::Neg};
 use itertools::iproduct;
 use lazy_static::lazy_static;
-use serde::{de, Deserialize, Serialize};
+#[cfg(not(feature = "std"))]
+use ring_buffer::RingBuffer;
+use serde::{Deserialize, Serialize, de};
 use strum::{EnumIter, IntoEnumIterator};
 
 pub mod render;
+pub mod replay;
+#[cfg(not(feature = "std"))]
+mod ring_buffer;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
 pub enum Direction {
@@ -131,35 +141,67 @@ impl<'de> Deserialize<'de> for Move {
         }
 
         let s: ArrayString<2> = ArrayString::deserialize(deserializer)?;
-        let cs: ArrayVec<char, 2> = s.chars().collect();
+        let mut cs = s.chars();
 
-        Ok(Move {
-            piece: Piece::from_char(cs[0]).ok_or(de::Error::unknown_variant(&s, &MOVE_VARIANTS))?,
-            direction: Direction::from_char(cs[1])
-                .ok_or(de::Error::unknown_variant(&s, &MOVE_VARIANTS))?,
-        })
+        cs.next()
+            .and_then(Piece::from_char)
+            .zip(cs.next().and_then(Direction::from_char))
+            .map(|(piece, direction)| Move { piece, direction })
+            .ok_or(de::Error::unknown_variant(&s, &MOVE_VARIANTS))
+    }
+}
+
+// A straight line of spaces that a piece slides along
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlideStrip {
+    pub top_left: Vector<u8>,
+    pub spaces: ArrayVec<Space, MAX_STRIP_SIZE>,
+}
+impl SlideStrip {
+    pub fn starting_position(&self, direction: Direction) -> Vector<u8> {
+        match direction {
+            Direction::Right | Direction::Down => self.top_left,
+            _ => self.top_left + (-direction).as_vector() * self.slide_distance() as i8,
+        }
+    }
+
+    pub fn ending_position(&self, direction: Direction) -> Vector<u8> {
+        self.starting_position(-direction)
+    }
+
+    pub fn ending_space(&self, direction: Direction) -> Space {
+        if direction.is_forward() {
+            *self.spaces.last().unwrap()
+        } else {
+            self.spaces[0]
+        }
+    }
+
+    pub fn slide_distance(&self) -> u8 {
+        self.spaces.len() as u8 - 1
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PieceSlid {
     pub muv: Move,
-    pub strip_top_left: Vector<u8>,
-    pub strip_spaces: ArrayVec<Space, MAX_STRIP_SIZE>,
+    // In the order the piece slides along them, with a second strip if the piece was teleported
+    pub strips: ArrayVec<SlideStrip, 2>,
 }
 impl PieceSlid {
     pub fn starting_position(&self) -> Vector<u8> {
-        match self.muv.direction {
-            Direction::Right | Direction::Down => self.strip_top_left,
-            _ => {
-                self.strip_top_left
-                    + (-self.muv.direction).as_vector() * self.slide_distance() as i8
-            }
-        }
+        self.strips[0].starting_position(self.muv.direction)
+    }
+
+    pub fn ending_position(&self) -> Vector<u8> {
+        self.strips
+            .last()
+            .unwrap()
+            .ending_position(self.muv.direction)
     }
 
     pub fn slide_distance(&self) -> u8 {
-        self.strip_spaces.len() as u8 - 1
+        self.strips.iter().map(|s| s.slide_distance()).sum()
     }
 }
 impl Neg for PieceSlid {
@@ -168,8 +210,7 @@ impl Neg for PieceSlid {
     fn neg(self) -> Self::Output {
         Self {
             muv: -self.muv,
-            strip_top_left: self.strip_top_left,
-            strip_spaces: self.strip_spaces,
+            strips: self.strips.into_iter().rev().collect(),
         }
     }
 }
@@ -190,12 +231,12 @@ pub struct PieceMoved {
 }
 
 #[derive(Clone)]
-struct LevelRunState<'a> {
-    pub level: &'a Level,
+pub(crate) struct LevelRunState<'a> {
+    pub level: &'a Level<'a>,
     pub positions: PieceMap<Vector<u8>>,
 }
-impl<'a> From<&'a Level> for LevelRunState<'a> {
-    fn from(value: &'a Level) -> Self {
+impl<'a> From<&'a Level<'a>> for LevelRunState<'a> {
+    fn from(value: &'a Level<'a>) -> Self {
         Self {
             level: value,
             positions: value.starting_positions.into(),
@@ -204,9 +245,11 @@ impl<'a> From<&'a Level> for LevelRunState<'a> {
 }
 impl LevelRunState<'_> {
     pub fn is_winning(&self) -> bool {
-        self.level
-            .all_pieces()
-            .all(|piece| self.level.get_space(self.piece_position(piece)) == Space::Goal(piece))
+        self.level.goal_pieces().all(|piece| {
+            self.level
+                .get_space(self.piece_position(piece))
+                .is_goal_for(piece)
+        })
     }
 
     #[inline]
@@ -219,70 +262,124 @@ impl LevelRunState<'_> {
         self.positions[piece] = new_position;
     }
 
+    // The moving piece has left its starting position, so it cannot block itself
+    fn is_occupied(&self, position: Vector<u8>, moving_piece: Piece) -> bool {
+        self.level
+            .all_pieces()
+            .any(|piece| piece != moving_piece && position == self.piece_position(piece))
+    }
+
+    fn strip(&self, from: Vector<u8>, to: Vector<u8>, direction: Direction) -> SlideStrip {
+        SlideStrip {
+            top_left: from.min(to),
+            spaces: if direction.is_horizontal() {
+                (from.x.min(to.x)..=from.x.max(to.x))
+                    .map(|x| self.level.get_space(Vector::new(x, to.y)))
+                    .collect()
+            } else {
+                (from.y.min(to.y)..=from.y.max(to.y))
+                    .map(|y| self.level.get_space(Vector::new(to.x, y)))
+                    .collect()
+            },
+        }
+    }
+
     pub fn attempt_move(&mut self, muv: Move) -> Option<PieceSlid> {
         let starting_position = self.piece_position(muv.piece);
-        let mut position = starting_position.clone();
+        let mut strip_start = starting_position;
+        let mut position = starting_position;
         let vector = muv.direction.as_vector();
+        let mut strips = ArrayVec::new();
 
-        // Move one space at a time until we hit a wall or another piece.
+        // Move one space at a time until we hit a wall or another piece, or land on a stop.
         let mut distance = 0;
         loop {
             let new_position = position + vector;
+            let new_space = self.level.get_space(new_position);
 
-            if self.level.get_space(new_position) == Space::Wall
-                || self
-                    .level
-                    .all_pieces()
-                    .any(|piece| new_position == self.piece_position(piece))
+            if new_space == Space::Wall
+                || matches!(new_space, Space::OneWay(d) if d != muv.direction)
+                || self.is_occupied(new_position, muv.piece)
             {
                 break;
             }
 
             distance += 1;
             position = new_position;
+
+            if new_space == Space::Stop {
+                break;
+            }
+
+            // Teleport at most once, and only if the other teleporter is not covered
+            if strips.is_empty()
+                && let Some(partner) = self.level.teleport_partner(position)
+                && !self.is_occupied(partner, muv.piece)
+            {
+                strips.push(self.strip(strip_start, position, muv.direction));
+                strip_start = partner;
+                position = partner;
+            }
         }
 
         if distance > 0 {
             self.positions[muv.piece] = position;
-            Some(PieceSlid {
-                muv,
-                strip_top_left: starting_position.min(position),
-                strip_spaces: if muv.direction.is_horizontal() {
-                    (starting_position.x.min(position.x)..=starting_position.x.max(position.x))
-                        .map(|x| self.level.get_space(Vector::new(x, position.y)))
-                        .collect()
-                } else {
-                    (starting_position.y.min(position.y)..=starting_position.y.max(position.y))
-                        .map(|y| self.level.get_space(Vector::new(position.x, y)))
-                        .collect()
-                },
-            })
+            strips.push(self.strip(strip_start, position, muv.direction));
+            Some(PieceSlid { muv, strips })
         } else {
             None
         }
     }
 }
 
+// Only this many of the latest moves can be undone, though any number can be made
 #[cfg(not(feature = "std"))]
-pub const MAX_MOVES: usize = 100;
-
-#[cfg(feature = "std")]
-trait VecExt {
-    fn is_full(&self) -> bool;
-}
-#[cfg(feature = "std")]
-impl<T> VecExt for Vec<T> {
-    fn is_full(&self) -> bool {
-        false
-    }
-}
+pub const MAX_UNDO_MOVES: usize = 100;
+// Limits how long the bounded solver can take to find a hint. Each node takes around 50 µs on the
+// PyGamer, so a hint can keep the game waiting for about a second before it gives up.
+#[cfg(not(feature = "std"))]
+pub const HINT_NODE_LIMIT: u32 = 20_000;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Action {
     Move(Direction),
     ChangeActivePiece,
+    PreviousActivePiece,
+    SelectPiece(Piece),
     UndoMove,
+    Redo,
     Restart,
+    Hint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    Move(Move),
+    Unsolvable,
+    // The solver gave up before finding a solution
+    GaveUp,
+}
+
+// Whether a win can still be reached from the current position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outlook {
+    // The number of moves remaining on a shortest path to a win
+    Solvable(u8),
+    DeadEnd,
+    // The solver gave up before finding out
+    Unknown,
+}
+
+// Result of searching for a shortest solution from the current position
+#[derive(Clone, Copy)]
+enum Search {
+    Solved {
+        first_move: Option<Move>,
+        num_moves: u8,
+    },
+    Unsolvable,
+    // The solver gave up, or the solution has too many moves to count
+    GaveUp,
 }
 
 #[derive(Debug, Clone)]
@@ -302,32 +399,140 @@ pub enum PiecesChanged<'a> {
 #[derive(Debug, Default, Clone)]
 pub struct LevelRunChange<'a> {
     pub pieces_changed: Option<PiecesChanged<'a>>,
-    pub num_moves_changed: Option<u8>,
+    pub num_moves_changed: Option<u16>,
     pub winning_status: Option<LevelStatus>,
     pub at_max_moves: bool,
+    pub hint: Option<Hint>,
+    pub outlook: Option<Outlook>,
 }
 
 pub struct LevelRun<'a> {
     level_num: u16,
+    rules: Rules,
     state: LevelRunState<'a>,
+    // Includes any moves that were dropped from the move stack and can no longer be undone
+    num_moves: u16,
     #[cfg(feature = "std")]
     move_stack: Vec<PieceSlid>,
     #[cfg(not(feature = "std"))]
-    move_stack: ArrayVec<PieceSlid, MAX_MOVES>,
+    move_stack: RingBuffer<PieceSlid, MAX_UNDO_MOVES>,
+    // Undone moves, which are lost once a new move is made
+    #[cfg(feature = "std")]
+    redo_stack: Vec<Move>,
+    #[cfg(not(feature = "std"))]
+    redo_stack: ArrayVec<Move, MAX_UNDO_MOVES>,
     active_piece: Piece,
+    // Kept until the pieces move so that the same position is never searched twice
+    search: Option<Search>,
 }
 impl<'a> LevelRun<'a> {
-    pub fn new(level_info: &LevelInfo) -> Self {
+    pub fn new(level_info: &LevelInfo<'a>, rules: &Rules) -> Self {
         Self {
             level_num: level_info.user_num(),
+            rules: *rules,
             state: LevelRunState::from(level_info.level),
+            num_moves: 0,
             move_stack: Default::default(),
+            redo_stack: Default::default(),
             active_piece: Default::default(),
+            search: None,
         }
     }
+
+    // Picks a level run back up where it was left, with the same moves able to be undone
+    pub fn resume(level_info: &LevelInfo<'a>, rules: &Rules, suspended_run: &SuspendedRun) -> Self {
+        Self::new(level_info, rules).restore(suspended_run)
+    }
+
+    fn restore(mut self, suspended_run: &SuspendedRun) -> Self {
+        let level = self.level();
+
+        // Start from where the pieces were before the moves that can be undone, or start over if
+        // they do not fit the level, as when it has since been changed
+        if !suspended_run.earlier_positions.is_empty() {
+            if suspended_run.earlier_positions.len() != level.all_pieces().count()
+                || suspended_run.earlier_positions.iter().any(|p| {
+                    p.x >= level.size.x || p.y >= level.size.y || !level.get_space(*p).is_playable()
+                })
+            {
+                return self;
+            }
+
+            for (piece, position) in level
+                .all_pieces()
+                .zip(suspended_run.earlier_positions.iter())
+            {
+                self.state.teleport_piece(piece, *position);
+            }
+            self.num_moves = suspended_run.num_earlier_moves;
+        }
+
+        // Stop at any move that cannot be made, as when the level has since been changed
+        for muv in suspended_run.moves.iter() {
+            if !level.all_pieces().any(|p| p == muv.piece) {
+                break;
+            }
+
+            match self.state.attempt_move(*muv) {
+                Some(piece_slid) if !self.at_max_moves() => {
+                    self.move_stack.push(piece_slid);
+                    self.num_moves += 1;
+                }
+                _ => break,
+            }
+        }
+
+        if level.all_pieces().any(|p| p == suspended_run.active_piece) {
+            self.active_piece = suspended_run.active_piece;
+        }
+
+        self
+    }
+
+    // There is nothing worth keeping if no moves have been made
+    pub fn suspend(&self) -> Option<SuspendedRun> {
+        (self.num_moves > 0).then(|| {
+            let num_earlier_moves = self.num_moves - self.move_stack.len() as u16;
+
+            // Work back to where the pieces were before the moves that can still be undone
+            let mut earlier_positions = ArrayVec::new();
+            if num_earlier_moves > 0 {
+                let mut state = self.state.clone();
+                for piece_slid in self.move_stack.iter().rev() {
+                    state.teleport_piece(piece_slid.muv.piece, piece_slid.starting_position());
+                }
+                earlier_positions
+                    .extend(self.level().all_pieces().map(|p| state.piece_position(p)));
+            }
+
+            SuspendedRun {
+                level_idx: self.level_num as usize - 1,
+                num_earlier_moves,
+                earlier_positions,
+                moves: self.move_stack.iter().map(|s| s.muv).collect(),
+                active_piece: self.active_piece,
+            }
+        })
+    }
+}
+
+// A level run that was left part way through so that it can be resumed later
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspendedRun {
+    pub level_idx: usize,
+    // Moves that were made before those that can be undone, and the positions they led to
+    #[serde(default)]
+    num_earlier_moves: u16,
+    #[serde(default, skip_serializing_if = "ArrayVec::is_empty")]
+    earlier_positions: ArrayVec<Vector<u8>, { variant_count::<Piece>() }>,
+    #[cfg(feature = "std")]
+    moves: Vec<Move>,
+    #[cfg(not(feature = "std"))]
+    moves: ArrayVec<Move, MAX_UNDO_MOVES>,
+    active_piece: Piece,
 }
 impl<'a> LevelRun<'a> {
-    pub fn level(&self) -> &'a Level {
+    pub fn level(&self) -> &'a Level<'a> {
         self.state.level
     }
 
@@ -335,15 +540,21 @@ impl<'a> LevelRun<'a> {
         &self.state.positions
     }
 
-    fn num_moves(&self) -> u8 {
-        self.move_stack.len() as u8
+    fn num_moves(&self) -> u16 {
+        self.num_moves
+    }
+
+    fn at_max_moves(&self) -> bool {
+        self.num_moves == u16::MAX
     }
 
     fn winning_status(&self) -> Option<LevelStatus> {
         self.state.is_winning().then(|| {
-            let rating = LevelRating::new(self.level().optimal_moves, self.num_moves());
+            let rating =
+                LevelRating::new(self.level().optimal_moves, self.num_moves(), &self.rules);
 
-            if rating.is_optimal() {
+            // A solution can only be kept if none of its moves were dropped
+            if rating.is_optimal() && self.move_stack.len() == self.num_moves() as usize {
                 LevelStatus::Optimal(self.move_stack.iter().map(|s| s.muv).collect())
             } else {
                 LevelStatus::Complete(rating)
@@ -358,8 +569,23 @@ impl<'a> LevelRun<'a> {
                 pieces_changed: Some(self.change_active_piece()),
                 ..Default::default()
             },
+            Action::PreviousActivePiece => LevelRunChange {
+                pieces_changed: Some(self.previous_active_piece()),
+                ..Default::default()
+            },
+            // Pieces that are not in the level cannot be selected
+            Action::SelectPiece(piece) => LevelRunChange {
+                pieces_changed: self
+                    .level()
+                    .all_pieces()
+                    .any(|p| p == piece)
+                    .then(|| self.set_active_piece(piece)),
+                ..Default::default()
+            },
             Action::UndoMove => self.undo_move(),
+            Action::Redo => self.redo_move(),
             Action::Restart => self.restart(),
+            Action::Hint => self.hint(),
         }
     }
 
@@ -371,10 +597,8 @@ impl<'a> LevelRun<'a> {
         // Try to move the active piece
         let mut moved = new_state.attempt_move(muv);
 
-        let mut change = LevelRunChange::default();
-
         // If the active piece cannot move, can the other piece?
-        if moved.is_none() {
+        if moved.is_none() && self.rules.auto_switch {
             for piece in self
                 .level()
                 .all_pieces()
@@ -394,10 +618,56 @@ impl<'a> LevelRun<'a> {
             }
         }
 
+        if let Some(ref slid) = moved {
+            self.update_redo_stack(slid.muv);
+        }
+
+        self.commit_move(new_state, moved, old_active_piece)
+    }
+
+    // Makes a specific move regardless of which piece is active, as when replaying a solution
+    fn play_move(&mut self, muv: Move) -> LevelRunChange {
+        let mut new_state = self.state.clone();
+        let moved = new_state.attempt_move(muv);
+        let mut old_active_piece = None;
+
+        if moved.is_some() {
+            self.update_redo_stack(muv);
+
+            if muv.piece != self.active_piece {
+                old_active_piece = Some(OldActivePiece {
+                    piece: self.active_piece,
+                    position: new_state.piece_position(self.active_piece),
+                });
+                self.active_piece = muv.piece;
+            }
+        }
+
+        self.commit_move(new_state, moved, old_active_piece)
+    }
+
+    // Making the next undone move again keeps the rest of them, but any other move loses them all
+    fn update_redo_stack(&mut self, muv: Move) {
+        if self.redo_stack.last() == Some(&muv) {
+            self.redo_stack.pop();
+        } else {
+            self.redo_stack.clear();
+        }
+    }
+
+    fn commit_move(
+        &mut self,
+        new_state: LevelRunState<'a>,
+        moved: Option<PieceSlid>,
+        old_active_piece: Option<OldActivePiece>,
+    ) -> LevelRunChange {
+        let mut change = LevelRunChange::default();
+
         if let Some(piece_slid) = moved
-            && !self.move_stack.is_full()
+            && !self.at_max_moves()
         {
             self.move_stack.push(piece_slid.clone());
+            self.num_moves += 1;
             self.state = new_state;
 
             change.pieces_changed = Some(PiecesChanged::Slid {
@@ -407,28 +677,155 @@ impl<'a> LevelRun<'a> {
             });
             change.num_moves_changed = Some(self.num_moves());
             change.winning_status = self.winning_status();
+            change.outlook = self.pieces_moved();
         }
-        change.at_max_moves = self.move_stack.is_full();
+        change.at_max_moves = self.at_max_moves();
 
         change
     }
 
     fn change_active_piece(&mut self) -> PiecesChanged {
-        let new_piece =
-            Piece::try_from((self.active_piece as u8 + 1) % self.level().num_pieces()).unwrap();
+        // Cycle through all the pieces, including any blockers
+        let new_piece = self
+            .level()
+            .all_pieces()
+            .skip_while(|p| *p != self.active_piece)
+            .nth(1)
+            .unwrap_or_default();
+
+        self.set_active_piece(new_piece)
+    }
+
+    // Same as above but in the other direction
+    fn previous_active_piece(&mut self) -> PiecesChanged<'_> {
+        let new_piece = self
+            .level()
+            .all_pieces()
+            .take_while(|p| *p != self.active_piece)
+            .last()
+            .or_else(|| self.level().all_pieces().last())
+            .unwrap_or_default();
+
+        self.set_active_piece(new_piece)
+    }
 
-        self.active_piece = new_piece;
+    fn set_active_piece(&mut self, piece: Piece) -> PiecesChanged<'_> {
+        self.active_piece = piece;
 
         PiecesChanged::ActivePiece {
-            active_piece: new_piece,
+            active_piece: piece,
             positions: &self.state.positions,
         }
     }
 
+    #[cfg(feature = "std")]
+    fn run_search(&self) -> Search {
+        match crate::solver::solve_from(self.level(), &self.state.positions) {
+            Some(moves) => match u8::try_from(moves.len()) {
+                Ok(num_moves) => Search::Solved {
+                    first_move: moves.first().copied(),
+                    num_moves,
+                },
+                Err(_) => Search::GaveUp,
+            },
+            None => Search::Unsolvable,
+        }
+    }
+
+    // Same as above but with fixed memory, so solutions longer than the longest level are not found
+    // and the search gives up on them instead
+    #[cfg(not(feature = "std"))]
+    fn run_search(&self) -> Search {
+        match solve_bounded::<MAX_OPTIMAL_MOVES>(
+            self.level(),
+            &self.state.positions,
+            HINT_NODE_LIMIT,
+        ) {
+            BoundedSolution::Solved(moves) => Search::Solved {
+                first_move: moves.first().copied(),
+                num_moves: moves.len() as u8,
+            },
+            BoundedSolution::Unsolvable => Search::Unsolvable,
+            BoundedSolution::TooLong | BoundedSolution::GaveUp => Search::GaveUp,
+        }
+    }
+
+    fn search(&mut self) -> Search {
+        match self.search {
+            Some(search) => search,
+            None => {
+                let search = self.run_search();
+                self.search = Some(search);
+                search
+            }
+        }
+    }
+
+    // Forgets the last search and returns the new outlook if it is known.
+    //
+    // Searching after every move takes too long on the device, so without std the outlook is only
+    // found along with a hint.
+    fn pieces_moved(&mut self) -> Option<Outlook> {
+        self.search = None;
+
+        #[cfg(feature = "std")]
+        self.search();
+
+        self.outlook()
+    }
+
+    // Determines the first move on a shortest path to a win, if the level is not already won
+    fn find_hint(&mut self) -> Option<Hint> {
+        match self.search() {
+            Search::Solved { first_move, .. } => first_move.map(Hint::Move),
+            Search::Unsolvable => Some(Hint::Unsolvable),
+            Search::GaveUp => Some(Hint::GaveUp),
+        }
+    }
+
+    // Only known once the current position has been searched
+    pub fn outlook(&self) -> Option<Outlook> {
+        self.search.map(|search| match search {
+            Search::Solved { num_moves, .. } => Outlook::Solvable(num_moves),
+            Search::Unsolvable => Outlook::DeadEnd,
+            Search::GaveUp => Outlook::Unknown,
+        })
+    }
+
+    fn hint(&mut self) -> LevelRunChange {
+        match self.find_hint() {
+            Some(Hint::Move(muv)) => {
+                // Make the suggested piece active so that it is highlighted
+                let pieces_changed = (muv.piece != self.active_piece).then(|| {
+                    self.active_piece = muv.piece;
+
+                    PiecesChanged::ActivePiece {
+                        active_piece: muv.piece,
+                        positions: &self.state.positions,
+                    }
+                });
+
+                LevelRunChange {
+                    pieces_changed,
+                    hint: Some(Hint::Move(muv)),
+                    ..Default::default()
+                }
+            }
+            Some(hint) => LevelRunChange {
+                hint: Some(hint),
+                ..Default::default()
+            },
+            // The level is already won
+            None => LevelRunChange::default(),
+        }
+    }
+
     pub fn undo_move(&mut self) -> LevelRunChange {
         if !self.move_stack.is_empty() {
             // Determine the inverse move
             let undo_slide = self.move_stack.pop().unwrap();
+            self.num_moves -= 1;
+            self.redo_stack.push(undo_slide.muv);
 
             // Apply the inverse move.
             self.state
@@ -442,6 +839,7 @@ impl<'a> LevelRun<'a> {
                     old_active_piece: None,
                 }),
                 num_moves_changed: Some(self.num_moves()),
+                outlook: self.pieces_moved(),
                 ..Default::default()
             }
         } else {
@@ -449,14 +847,24 @@ impl<'a> LevelRun<'a> {
         }
     }
 
+    // Plays the most recently undone move again
+    fn redo_move(&mut self) -> LevelRunChange {
+        match self.redo_stack.last() {
+            Some(muv) => self.play_move(*muv),
+            None => LevelRunChange::default(),
+        }
+    }
+
     pub fn restart(&mut self) -> LevelRunChange {
-        if !self.move_stack.is_empty() {
+        if self.num_moves > 0 {
             // We need to clear the stack
             let old_state = self.state.clone();
 
             // Reset the state
             self.state = LevelRunState::from(self.level());
+            self.num_moves = 0;
             self.move_stack.clear();
+            self.redo_stack.clear();
 
             // Reset the active piece
             self.active_piece = Piece::default();
@@ -478,6 +886,7 @@ impl<'a> LevelRun<'a> {
                         .collect(),
                 )),
                 num_moves_changed: Some(self.num_moves()),
+                outlook: self.pieces_moved(),
                 ..Default::default()
             }
         } else {
@@ -486,4 +895,534 @@ impl<'a> LevelRun<'a> {
     }
 }
 
-// TODO: Write some tests!
+#[cfg(test)]
+impl<'a> LevelRun<'a> {
+    // Starts a run with the default rules, which is all most tests need
+    pub(crate) fn for_test(index: usize, level: &'a Level<'a>) -> Self {
+        Self::new(
+            &LevelInfo {
+                index,
+                rating: LevelRating::default(),
+                level,
+            },
+            &Rules::default(),
+        )
+    }
+}
+
+// TODO: Write some more tests!
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{
+        levels::LEVELS,
+        owned_level::OwnedLevel,
+        solver::{bounded::solve_level, solve, solve_from},
+    };
+
+    #[test]
+    fn outlook() {
+        let level = &LEVELS[4];
+        let mut level_run = LevelRun::for_test(4, level);
+        assert_eq!(level_run.outlook(), None);
+        level_run.execute_action(Action::Hint);
+        assert_eq!(
+            level_run.outlook(),
+            Some(Outlook::Solvable(level.optimal_moves))
+        );
+
+        // Following the solution should count down the remaining moves
+        for (idx, muv) in solve(level).unwrap().into_iter().enumerate() {
+            level_run.active_piece = muv.piece;
+            let change = level_run.execute_action(Action::Move(muv.direction));
+
+            assert_eq!(
+                change.outlook,
+                Some(Outlook::Solvable(level.optimal_moves - idx as u8 - 1))
+            );
+        }
+
+        assert_eq!(
+            level_run.execute_action(Action::UndoMove).outlook,
+            Some(Outlook::Solvable(1))
+        );
+        assert_eq!(
+            level_run.execute_action(Action::Restart).outlook,
+            Some(Outlook::Solvable(level.optimal_moves))
+        );
+    }
+
+    #[test]
+    fn hint() {
+        let level = &LEVELS[4];
+        let mut level_run = LevelRun::for_test(4, level);
+
+        // The hinted piece becomes active
+        let Some(Hint::Move(muv)) = level_run.find_hint() else {
+            panic!("level should have a hint")
+        };
+        level_run.active_piece = level.all_pieces().find(|p| *p != muv.piece).unwrap();
+        let change = level_run.execute_action(Action::Hint);
+        assert_eq!(change.hint, Some(Hint::Move(muv)));
+        assert!(matches!(
+            change.pieces_changed,
+            Some(PiecesChanged::ActivePiece { active_piece, .. }) if active_piece == muv.piece
+        ));
+        assert_eq!(level_run.active_piece, muv.piece);
+
+        // The hinted move is on a shortest path to a win
+        let change = level_run.execute_action(Action::Move(muv.direction));
+        assert_eq!(
+            change.outlook,
+            Some(Outlook::Solvable(level.optimal_moves - 1))
+        );
+
+        // There is nothing to hint once the level is won
+        for muv in solve_from(level, &level_run.state.positions).unwrap() {
+            level_run.play_move(muv);
+        }
+        assert!(level_run.state.is_winning());
+        let change = level_run.execute_action(Action::Hint);
+        assert!(change.hint.is_none());
+        assert!(change.pieces_changed.is_none());
+    }
+
+    #[test]
+    fn redo() {
+        let level = &LEVELS[4];
+        let mut level_run = LevelRun::for_test(4, level);
+        let solution = solve(level).unwrap();
+        for muv in solution.iter() {
+            level_run.play_move(*muv);
+        }
+        let won_positions = level_run.state.positions.clone();
+
+        // Undone moves can be redone in order, up to winning again
+        level_run.execute_action(Action::UndoMove);
+        level_run.execute_action(Action::UndoMove);
+        level_run.execute_action(Action::ChangeActivePiece);
+        let change = level_run.execute_action(Action::Redo);
+        assert!(matches!(
+            change.pieces_changed,
+            Some(PiecesChanged::Slid { .. })
+        ));
+        assert_eq!(level_run.active_piece, solution[solution.len() - 2].piece);
+        let change = level_run.execute_action(Action::Redo);
+        assert!(change.winning_status.is_some());
+        assert_eq!(level_run.state.positions, won_positions);
+        assert!(
+            level_run
+                .execute_action(Action::Redo)
+                .pieces_changed
+                .is_none()
+        );
+
+        // A different move loses anything undone
+        level_run.execute_action(Action::UndoMove);
+        level_run.execute_action(Action::UndoMove);
+        let muv = Direction::iter()
+            .map(|d| Move::new(level_run.active_piece, d))
+            .find(|m| {
+                *m != solution[solution.len() - 2]
+                    && level_run.state.clone().attempt_move(*m).is_some()
+            })
+            .unwrap();
+        level_run.execute_action(Action::Move(muv.direction));
+        assert!(
+            level_run
+                .execute_action(Action::Redo)
+                .pieces_changed
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn suspend() {
+        let level = &LEVELS[4];
+        let mut level_run = LevelRun::for_test(4, level);
+        assert_eq!(level_run.suspend(), None);
+
+        let solution = solve(level).unwrap();
+        for muv in solution.iter().take(solution.len() - 1) {
+            level_run.play_move(*muv);
+        }
+        level_run.execute_action(Action::ChangeActivePiece);
+
+        // The run comes back with its undo history
+        let suspended_run = level_run.suspend().unwrap();
+        assert_eq!(suspended_run.level_idx, 4);
+        let mut resumed_run = LevelRun::for_test(4, level).restore(&suspended_run);
+        assert_eq!(resumed_run.state.positions, level_run.state.positions);
+        assert_eq!(resumed_run.active_piece, level_run.active_piece);
+        assert_eq!(resumed_run.num_moves(), level_run.num_moves());
+        resumed_run.execute_action(Action::UndoMove);
+        resumed_run.execute_action(Action::Redo);
+        let change = resumed_run.play_move(*solution.last().unwrap());
+        assert!(matches!(
+            change.winning_status,
+            Some(LevelStatus::Optimal(_))
+        ));
+
+        // Moves stop at a piece the level does not have
+        let mut bad_run = suspended_run.clone();
+        bad_run
+            .moves
+            .insert(1, Move::new(Piece::Blue, Direction::Up));
+        let resumed_run = LevelRun::for_test(4, level).restore(&bad_run);
+        assert_eq!(resumed_run.num_moves(), 1);
+
+        // Positions that do not fit the level start the run over
+        let mut bad_run = suspended_run;
+        bad_run.num_earlier_moves = 1;
+        let num_pieces = level.all_pieces().count();
+        for earlier_positions in [
+            vec![Vector::new(50, 50); num_pieces],
+            vec![Vector::new(0, 0); num_pieces],
+            vec![level.starting_positions.pieces[0]],
+        ] {
+            bad_run.earlier_positions = earlier_positions.into_iter().collect();
+            let resumed_run = LevelRun::for_test(4, level).restore(&bad_run);
+            assert_eq!(resumed_run.num_moves(), 0);
+            assert_eq!(resumed_run.state.positions, level.starting_positions.into());
+        }
+    }
+
+    #[test]
+    fn active_piece() {
+        let level: OwnedLevel = "
+######
+#g G #
+#oO x#
+######
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let mut level_run = LevelRun::for_test(0, &level);
+
+        // Going backwards wraps around to the last piece
+        level_run.execute_action(Action::PreviousActivePiece);
+        assert_eq!(level_run.active_piece, Piece::Grey1);
+        level_run.execute_action(Action::PreviousActivePiece);
+        assert_eq!(level_run.active_piece, Piece::Orange);
+
+        let change = level_run.execute_action(Action::SelectPiece(Piece::Grey1));
+        assert!(matches!(
+            change.pieces_changed,
+            Some(PiecesChanged::ActivePiece {
+                active_piece: Piece::Grey1,
+                ..
+            })
+        ));
+        assert!(
+            level_run
+                .execute_action(Action::SelectPiece(Piece::Blue))
+                .pieces_changed
+                .is_none()
+        );
+        assert_eq!(level_run.active_piece, Piece::Grey1);
+    }
+
+    #[test]
+    fn auto_switch() {
+        let level: OwnedLevel = "
+######
+#g  G#
+#O  o#
+######
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+
+        // The green piece cannot move left, so the orange one does instead unless switching is off
+        let mut level_run = LevelRun::for_test(0, &level);
+        let change = level_run.execute_action(Action::Move(Direction::Left));
+        assert!(change.pieces_changed.is_some());
+        assert_eq!(level_run.active_piece, Piece::Orange);
+
+        let rules = Rules {
+            auto_switch: false,
+            ..Default::default()
+        };
+        let mut level_run = LevelRun {
+            rules,
+            ..LevelRun::for_test(0, &level)
+        };
+        let change = level_run.execute_action(Action::Move(Direction::Left));
+        assert!(change.pieces_changed.is_none());
+        assert_eq!(level_run.active_piece, Piece::Green);
+    }
+
+    #[test]
+    fn stop() {
+        let level: OwnedLevel = "
+#######
+#g * G#
+#######
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let mut state = LevelRunState::from(&level);
+
+        // The piece stops on the stop space, but can then leave it
+        let slid = state
+            .attempt_move(Move::new(Piece::Green, Direction::Right))
+            .unwrap();
+        assert_eq!(state.piece_position(Piece::Green), Vector::new(3, 1));
+        assert_eq!(slid.slide_distance(), 2);
+        assert_eq!(slid.strips[0].spaces.last(), Some(&Space::Stop));
+
+        state.attempt_move(Move::new(Piece::Green, Direction::Right));
+        assert!(state.is_winning());
+        assert_eq!(level.optimal_moves, 2);
+    }
+
+    #[test]
+    fn one_way() {
+        let level: OwnedLevel = "
+#######
+#g > G#
+#O < o#
+#######
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let mut state = LevelRunState::from(&level);
+
+        // Pieces can cross a gate in its direction, but it is a wall otherwise
+        state.attempt_move(Move::new(Piece::Green, Direction::Right));
+        assert_eq!(state.piece_position(Piece::Green), Vector::new(5, 1));
+        let slid = state
+            .attempt_move(Move::new(Piece::Green, Direction::Left))
+            .unwrap();
+        assert_eq!(state.piece_position(Piece::Green), Vector::new(4, 1));
+        assert_eq!(slid.slide_distance(), 1);
+
+        state.attempt_move(Move::new(Piece::Orange, Direction::Left));
+        assert_eq!(state.piece_position(Piece::Orange), Vector::new(1, 2));
+        assert_eq!(level.optimal_moves, 2);
+    }
+
+    #[test]
+    fn teleport() {
+        let level: OwnedLevel = "
+########
+#g 1 1G#
+#O    o#
+########
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let mut level_run = LevelRun::for_test(0, &level);
+
+        // The piece comes out of the other teleporter and keeps sliding
+        let change = level_run.execute_action(Action::Move(Direction::Right));
+        let Some(PiecesChanged::Slid { piece_slid, .. }) = change.pieces_changed else {
+            panic!("piece should have slid")
+        };
+        assert_eq!(piece_slid.strips.len(), 2);
+        assert_eq!(piece_slid.slide_distance(), 3);
+        assert_eq!(piece_slid.starting_position(), Vector::new(1, 1));
+        assert_eq!(piece_slid.ending_position(), Vector::new(6, 1));
+        assert_eq!(
+            level_run.state.piece_position(Piece::Green),
+            Vector::new(6, 1)
+        );
+
+        // Undoing reverses the slide through the teleporters
+        let change = level_run.execute_action(Action::UndoMove);
+        let Some(PiecesChanged::Slid { piece_slid, .. }) = change.pieces_changed else {
+            panic!("piece should have slid back")
+        };
+        assert_eq!(piece_slid.starting_position(), Vector::new(6, 1));
+        assert_eq!(piece_slid.ending_position(), Vector::new(1, 1));
+        assert_eq!(
+            level_run.state.piece_position(Piece::Green),
+            Vector::new(1, 1)
+        );
+
+        // A teleporter with a piece on its partner is just a free space
+        let mut state = LevelRunState::from(&level);
+        state.teleport_piece(Piece::Orange, Vector::new(5, 1));
+        let slid = state
+            .attempt_move(Move::new(Piece::Green, Direction::Right))
+            .unwrap();
+        assert_eq!(slid.strips.len(), 1);
+        assert_eq!(state.piece_position(Piece::Green), Vector::new(4, 1));
+        assert_eq!(level.optimal_moves, 2);
+
+        // The piece can slide back over the space it started from
+        let level: OwnedLevel = "
+########
+#1g 1 G#
+########
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let mut state = LevelRunState::from(&level);
+        let slid = state
+            .attempt_move(Move::new(Piece::Green, Direction::Right))
+            .unwrap();
+        assert_eq!(slid.strips.len(), 2);
+        assert_eq!(state.piece_position(Piece::Green), Vector::new(6, 1));
+        assert_eq!(level.optimal_moves, 1);
+    }
+
+    #[test]
+    fn blockers() {
+        let owned_level: OwnedLevel = "
+######
+#g G #
+#   x#
+######
+"
+        .parse()
+        .unwrap();
+        assert_eq!(owned_level.to_string().parse(), Ok(owned_level.clone()));
+        let level = owned_level.level();
+        assert_eq!(level.num_pieces(), 1);
+        assert_eq!(level.optimal_moves, 2);
+
+        // Blockers can be made active and moved, but do not need goals to win
+        let mut level_run = LevelRun::for_test(0, &level);
+        level_run.execute_action(Action::ChangeActivePiece);
+        assert_eq!(level_run.active_piece, Piece::Grey1);
+        level_run.execute_action(Action::Move(Direction::Up));
+        level_run.execute_action(Action::ChangeActivePiece);
+        assert_eq!(level_run.active_piece, Piece::Green);
+        let change = level_run.execute_action(Action::Move(Direction::Right));
+        assert!(matches!(
+            change.winning_status,
+            Some(LevelStatus::Optimal(_))
+        ));
+    }
+
+    #[test]
+    fn goals() {
+        let level: OwnedLevel = "
+######
+#G  o#
+#g  +#
+#   G#
+######
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let mut state = LevelRunState::from(&level);
+        assert!(!state.is_winning());
+
+        // Either green goal will do, and the orange piece can finish on the wildcard
+        state.teleport_piece(Piece::Green, Vector::new(1, 1));
+        state.teleport_piece(Piece::Orange, Vector::new(4, 2));
+        assert!(state.is_winning());
+        state.teleport_piece(Piece::Green, Vector::new(4, 3));
+        assert!(state.is_winning());
+        state.teleport_piece(Piece::Orange, Vector::new(1, 2));
+        assert!(!state.is_winning());
+
+        assert_eq!(solve_level(&level).len(), level.optimal_moves as usize);
+    }
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use super::*;
+    use crate::{
+        levels::LEVELS,
+        solver::bounded::{BoundedSolution, solve_bounded},
+    };
+
+    #[test]
+    fn undo_limit() {
+        let mut level_run = LevelRun::for_test(0, &LEVELS[0]);
+
+        // Slide the second piece back and forth along the top row, well past what can be undone
+        let piece = level_run.level().all_pieces().nth(1).unwrap();
+        let num_moves = MAX_UNDO_MOVES + 50;
+        let mut earlier_positions = level_run.state.positions.clone();
+        for idx in 0..num_moves {
+            if idx == num_moves - MAX_UNDO_MOVES {
+                earlier_positions = level_run.state.positions.clone();
+            }
+            let direction = if idx % 2 == 0 {
+                Direction::Right
+            } else {
+                Direction::Left
+            };
+            let change = level_run.play_move(Move::new(piece, direction));
+            assert!(change.pieces_changed.is_some());
+            assert!(change.winning_status.is_none());
+
+            // Searching after every move would be too slow
+            assert!(change.outlook.is_none());
+        }
+        assert_eq!(level_run.num_moves() as usize, num_moves);
+
+        // The run comes back with only the moves that can be undone
+        level_run.execute_action(Action::UndoMove);
+        let suspended_run = level_run.suspend().unwrap();
+        assert_eq!(
+            suspended_run.num_earlier_moves as usize,
+            num_moves - MAX_UNDO_MOVES
+        );
+        assert!(
+            suspended_run.earlier_positions.iter().eq(level_run
+                .level()
+                .all_pieces()
+                .map(|p| &earlier_positions[p]))
+        );
+        assert_eq!(suspended_run.moves.len(), MAX_UNDO_MOVES - 1);
+        let mut level_run = LevelRun::for_test(0, &LEVELS[0]).restore(&suspended_run);
+        assert_eq!(level_run.num_moves() as usize, num_moves - 1);
+
+        // Undoing stops at the earliest move that was kept
+        for _ in 0..MAX_UNDO_MOVES - 1 {
+            assert!(
+                level_run
+                    .execute_action(Action::UndoMove)
+                    .pieces_changed
+                    .is_some()
+            );
+        }
+        assert!(
+            level_run
+                .execute_action(Action::UndoMove)
+                .pieces_changed
+                .is_none()
+        );
+        assert_eq!(level_run.num_moves() as usize, num_moves - MAX_UNDO_MOVES);
+        assert_eq!(level_run.state.positions, earlier_positions);
+
+        // Winning with moves that were dropped cannot keep the solution
+        let BoundedSolution::Solved(solution) = solve_bounded::<MAX_OPTIMAL_MOVES>(
+            level_run.level(),
+            &level_run.state.positions,
+            u32::MAX,
+        ) else {
+            panic!("level should be solvable");
+        };
+        assert_eq!(level_run.outlook(), None);
+        assert_eq!(
+            level_run.execute_action(Action::Hint).hint,
+            Some(Hint::Move(solution[0]))
+        );
+        assert_eq!(
+            level_run.outlook(),
+            Some(Outlook::Solvable(solution.len() as u8))
+        );
+        let mut change = LevelRunChange::default();
+        for muv in solution {
+            change = level_run.play_move(muv);
+        }
+        assert!(matches!(
+            change.winning_status,
+            Some(LevelStatus::Complete(_))
+        ));
+    }
+}
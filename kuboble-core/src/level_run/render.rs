@@ -1,17 +1,16 @@
-use super::{LevelRun, LevelRunChange, PieceSlid, PiecesChanged};
-use crate::{level_select::LevelStatus, BufferedRenderer, Piece, Space, Vector};
-
-#[cfg(feature = "std")]
-use super::VecExt;
+use super::{Hint, LevelRun, LevelRunChange, Outlook, PieceSlid, PiecesChanged};
+use crate::{BufferedRenderer, Piece, Space, Vector, level_select::LevelStatus};
 
 pub trait LevelRunRenderer: BufferedRenderer {
     fn draw_space(&mut self, position: Vector<u8>, space: Space);
     fn draw_piece(&mut self, position: Vector<u8>, piece: Piece, is_active: bool);
     fn slide_piece(&mut self, piece_slid: &PieceSlid, is_active: bool);
-    fn update_num_moves(&mut self, num_moves: u8, at_maximum: bool);
+    fn update_num_moves(&mut self, num_moves: u16, at_maximum: bool);
     fn update_constants(&mut self, level_num: u16, goal: u8);
     fn update_active_piece(&mut self, piece: Piece);
     fn notify_win(&mut self, level_status: LevelStatus);
+    fn show_hint(&mut self, hint: Hint);
+    fn update_outlook(&mut self, outlook: Outlook);
 }
 
 impl LevelRun<'_> {
@@ -34,9 +33,12 @@ impl LevelRun<'_> {
         }
 
         // Update metrics
-        renderer.update_num_moves(self.num_moves(), self.move_stack.is_full());
+        renderer.update_num_moves(self.num_moves(), self.at_max_moves());
         renderer.update_constants(self.level_num, level.optimal_moves);
         renderer.update_active_piece(self.active_piece);
+        if let Some(outlook) = self.outlook() {
+            renderer.update_outlook(outlook);
+        }
 
         // Display alert if applicable
         if let Some(status) = self.winning_status() {
@@ -100,6 +102,14 @@ impl LevelRunChange<'_> {
             renderer.update_num_moves(n, self.at_max_moves);
         }
 
+        if let Some(outlook) = self.outlook {
+            renderer.update_outlook(outlook);
+        }
+
+        if let Some(hint) = self.hint {
+            renderer.show_hint(hint);
+        }
+
         if let Some(status) = self.winning_status.clone() {
             renderer.notify_win(status);
         }
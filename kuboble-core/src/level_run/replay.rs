@@ -0,0 +1,106 @@
+use super::{LevelRun, LevelRunChange, Move, render::LevelRunRenderer};
+use crate::{Rules, level_select::LevelInfo, levels::MAX_OPTIMAL_MOVES};
+use arrayvec::ArrayVec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayAction {
+    StepForward,
+    StepBack,
+    Restart,
+}
+
+// Plays back a stored solution one move at a time
+pub struct LevelReplay<'a> {
+    level_run: LevelRun<'a>,
+    moves: ArrayVec<Move, MAX_OPTIMAL_MOVES>,
+    num_played: usize,
+}
+impl<'a> LevelReplay<'a> {
+    pub fn new(level_info: &LevelInfo<'a>, moves: ArrayVec<Move, MAX_OPTIMAL_MOVES>) -> Self {
+        Self {
+            // The rules make no difference to playing back a solution
+            level_run: LevelRun::new(level_info, &Rules::default()),
+            moves,
+            num_played: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.num_played >= self.moves.len()
+    }
+
+    pub fn execute_action(&mut self, action: ReplayAction) -> LevelRunChange {
+        let mut change = match action {
+            ReplayAction::StepForward => match self.moves.get(self.num_played) {
+                Some(muv) => {
+                    let change = self.level_run.play_move(*muv);
+
+                    // Stop if the stored solution is not actually valid
+                    if change.pieces_changed.is_some() {
+                        self.num_played += 1;
+                    } else {
+                        self.num_played = self.moves.len();
+                    }
+                    change
+                }
+                None => LevelRunChange::default(),
+            },
+            ReplayAction::StepBack => {
+                self.num_played = self.num_played.saturating_sub(1);
+                self.level_run.undo_move()
+            }
+            ReplayAction::Restart => {
+                self.num_played = 0;
+                self.level_run.restart()
+            }
+        };
+
+        // The replayed level is already complete, so there is nothing to notify
+        change.winning_status = None;
+        change
+    }
+
+    pub fn render<R: LevelRunRenderer>(&self, renderer: &mut R) {
+        self.level_run.render(renderer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LevelRating, levels::LEVELS, solver::bounded::solve_level};
+
+    #[test]
+    fn replay() {
+        let level_info = LevelInfo {
+            index: 0,
+            rating: LevelRating::default(),
+            level: &LEVELS[0],
+        };
+        let mut replay = LevelReplay::new(&level_info, ArrayVec::new());
+        assert!(replay.is_finished());
+        assert!(
+            replay
+                .execute_action(ReplayAction::StepForward)
+                .pieces_changed
+                .is_none()
+        );
+
+        // Play a winning move sequence forwards and backwards
+        let mut replay = LevelReplay::new(&level_info, solve_level(level_info.level));
+        for _ in 0..level_info.level.optimal_moves {
+            assert!(!replay.is_finished());
+            let change = replay.execute_action(ReplayAction::StepForward);
+            assert!(change.pieces_changed.is_some());
+            assert!(change.winning_status.is_none());
+        }
+        assert!(replay.is_finished());
+        assert!(replay.level_run.state.is_winning());
+
+        replay.execute_action(ReplayAction::StepBack);
+        assert!(!replay.is_finished());
+        assert!(!replay.level_run.state.is_winning());
+        replay.execute_action(ReplayAction::Restart);
+        assert_eq!(replay.level_run.num_moves(), 0);
+    }
+}
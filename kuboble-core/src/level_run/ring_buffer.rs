@@ -0,0 +1,89 @@
+// A stack with fixed memory that drops its oldest item to make room once it is full
+pub struct RingBuffer<T, const N: usize> {
+    items: [Option<T>; N],
+    // Index of the oldest item
+    start: usize,
+    len: usize,
+}
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self {
+            items: core::array::from_fn(|_| None),
+            start: 0,
+            len: 0,
+        }
+    }
+}
+impl<T, const N: usize> RingBuffer<T, N> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, item: T) {
+        // When full this is the slot of the oldest item
+        self.items[(self.start + self.len) % N] = Some(item);
+
+        if self.len < N {
+            self.len += 1;
+        } else {
+            self.start = (self.start + 1) % N;
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.len -= 1;
+            self.items[(self.start + self.len) % N].take()
+        }
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+        self.start = 0;
+    }
+
+    // From oldest to newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        (0..self.len).map(|idx| self.items[(self.start + idx) % N].as_ref().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_buffer() {
+        let mut buffer: RingBuffer<u8, 3> = RingBuffer::default();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.pop(), None);
+
+        // The oldest items are lost once full
+        for item in 1..=5 {
+            buffer.push(item);
+        }
+        assert_eq!(buffer.len(), 3);
+        assert!(buffer.iter().copied().eq([3, 4, 5]));
+        assert_eq!(buffer.pop(), Some(5));
+        buffer.push(6);
+        buffer.push(7);
+        assert!(buffer.iter().copied().eq([4, 6, 7]));
+        assert_eq!(buffer.pop(), Some(7));
+        assert_eq!(buffer.pop(), Some(6));
+        assert_eq!(buffer.pop(), Some(4));
+        assert_eq!(buffer.pop(), None);
+
+        buffer.push(8);
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.iter().count(), 0);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
-    level_run::Move,
+    Level, LevelRating, Rules,
+    level_run::{Move, SuspendedRun},
     levels::{LEVELS, MAX_OPTIMAL_MOVES, NUM_LEVELS},
-    Level, LevelRating,
 };
 use arrayvec::ArrayVec;
 use core::{cmp::Ordering, iter::repeat, mem::discriminant};
@@ -110,23 +110,45 @@ impl Filter {
 }
 
 #[derive(Clone, Debug)]
-pub struct LevelInfo {
+pub struct LevelInfo<'a> {
     pub index: usize,
     pub rating: LevelRating,
-    pub level: &'static Level,
+    pub level: &'a Level<'a>,
 }
-impl LevelInfo {
+impl LevelInfo<'_> {
     pub fn user_num(&self) -> u16 {
         self.index as u16 + 1
     }
 }
 
+// Without the standard library only the most recently suspended runs are kept to limit memory use
+#[cfg(not(feature = "std"))]
+pub const MAX_SUSPENDED_RUNS: usize = 8;
+
 #[derive(Default, Serialize, Deserialize)]
 pub struct LevelProgress {
     level_statuses: ArrayVec<LevelStatus, NUM_LEVELS>,
+    // In the order they were suspended, at most one per level
+    #[cfg(feature = "std")]
+    #[serde(default)]
+    suspended_runs: Vec<SuspendedRun>,
+    #[cfg(not(feature = "std"))]
+    #[serde(default)]
+    suspended_runs: ArrayVec<SuspendedRun, MAX_SUSPENDED_RUNS>,
+    // These are chosen by the player rather than saved with their progress
+    #[serde(skip)]
+    rules: Rules,
 }
 impl LevelProgress {
-    pub fn level_info(&self, level_idx: usize) -> LevelInfo {
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    pub fn set_rules(&mut self, rules: Rules) {
+        self.rules = rules;
+    }
+
+    pub fn level_info(&self, level_idx: usize) -> LevelInfo<'static> {
         let level = &LEVELS[level_idx];
 
         LevelInfo {
@@ -140,6 +162,13 @@ impl LevelProgress {
         }
     }
 
+    pub fn level_status(&self, level_idx: usize) -> LevelStatus {
+        self.level_statuses
+            .get(level_idx)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     #[cfg(not(feature = "unlocked"))]
     pub fn num_unlocked_levels(&self) -> usize {
         NUM_LEVELS.min(
@@ -147,7 +176,7 @@ impl LevelProgress {
                 .iter()
                 .filter(|l| l.is_complete())
                 .count()
-                + 10,
+                + self.rules.num_unlocked,
         )
     }
 
@@ -160,6 +189,31 @@ impl LevelProgress {
         NUM_LEVELS - self.num_unlocked_levels()
     }
 
+    // Replays every stored optimal solution, downgrading any that do not actually win the level
+    // in the optimal number of moves, and returns the number of statuses that were changed.
+    pub fn verify_solutions(&mut self) -> usize {
+        let mut num_changed = 0;
+
+        for (status, level) in self.level_statuses.iter_mut().zip(LEVELS.iter()) {
+            if let LevelStatus::Optimal(moves) = status {
+                let new_status = match level.verify_solution(moves) {
+                    Ok(num_moves) if num_moves <= level.optimal_moves => continue,
+                    Ok(num_moves) => LevelStatus::Complete(LevelRating::new(
+                        level.optimal_moves,
+                        num_moves.into(),
+                        &self.rules,
+                    )),
+                    Err(_) => LevelStatus::Incomplete,
+                };
+
+                *status = new_status;
+                num_changed += 1;
+            }
+        }
+
+        num_changed
+    }
+
     // Only updates the status if it is better and returns whether it was updated
     pub fn attempt_status_update(&mut self, level_idx: usize, new_status: LevelStatus) -> bool {
         if level_idx >= self.level_statuses.len() {
@@ -178,6 +232,29 @@ impl LevelProgress {
         }
     }
 
+    pub fn suspended_run(&self, level_idx: usize) -> Option<&SuspendedRun> {
+        self.suspended_runs
+            .iter()
+            .find(|r| r.level_idx == level_idx)
+    }
+
+    // Replaces any run already suspended for the level
+    pub fn suspend_run(&mut self, suspended_run: SuspendedRun) {
+        self.clear_suspended_run(suspended_run.level_idx);
+
+        // There is only room for so many runs without the standard library, so the one suspended
+        // longest ago is lost
+        #[cfg(not(feature = "std"))]
+        if self.suspended_runs.is_full() {
+            self.suspended_runs.remove(0);
+        }
+        self.suspended_runs.push(suspended_run);
+    }
+
+    pub fn clear_suspended_run(&mut self, level_idx: usize) {
+        self.suspended_runs.retain(|r| r.level_idx != level_idx);
+    }
+
     pub fn filtered_indices(&self, filter: Filter) -> impl Iterator<Item = usize> + '_ {
         static DEFAULT_STATUS: LevelStatus = LevelStatus::Incomplete;
 
@@ -381,13 +458,15 @@ pub enum Action {
     ChangePage(Direction),
     ChangeActiveFilter(Direction),
     ActiveLevelCompleted(LevelStatus),
+    // The active level was left part way through, with nothing to keep if no moves were made
+    ActiveLevelSuspended(Option<SuspendedRun>),
 }
 
 #[derive(Debug)]
 pub enum LevelSlotInfo {
     Empty(u8),
     Level {
-        level_info: LevelInfo,
+        level_info: LevelInfo<'static>,
         position: u8,
         is_active: bool,
     },
@@ -438,11 +517,21 @@ impl<'a, const W: usize> LevelSelector<'a, W> {
             .map(|idx| *idx as usize)
     }
 
-    pub fn active_level_info(&self) -> Option<LevelInfo> {
+    pub fn active_level_info(&self) -> Option<LevelInfo<'static>> {
         self.active_level_idx()
             .map(|level_idx| self.level_progress.level_info(level_idx))
     }
 
+    pub fn active_level_status(&self) -> Option<LevelStatus> {
+        self.active_level_idx()
+            .map(|level_idx| self.level_progress.level_status(level_idx))
+    }
+
+    pub fn active_suspended_run(&self) -> Option<SuspendedRun> {
+        self.active_level_idx()
+            .and_then(|level_idx| self.level_progress.suspended_run(level_idx).cloned())
+    }
+
     pub fn active_rating(&self) -> Option<LevelRating> {
         self.active_level_info().map(|l| l.rating)
     }
@@ -563,6 +652,9 @@ impl<'a, const W: usize> LevelSelector<'a, W> {
                 let level_idx = self.active_level_idx();
 
                 level_idx.and_then(|level_idx| {
+                    // The level is finished so there is no longer anything to resume
+                    self.level_progress.clear_suspended_run(level_idx);
+
                     self.level_progress
                         .attempt_status_update(level_idx, new_status)
                         .then(|| {
@@ -594,6 +686,17 @@ impl<'a, const W: usize> LevelSelector<'a, W> {
                         })
                 })
             }
+            Action::ActiveLevelSuspended(suspended_run) => {
+                if let Some(level_idx) = self.active_level_idx() {
+                    match suspended_run {
+                        Some(suspended_run) => self.level_progress.suspend_run(suspended_run),
+                        None => self.level_progress.clear_suspended_run(level_idx),
+                    }
+                }
+
+                // Nothing is shown for suspended runs
+                None
+            }
         }
     }
 }
@@ -606,6 +709,81 @@ impl<'a, const W: usize> LevelSelector<'a, W> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn verify_solutions() {
+        use crate::{Piece, level_run::Direction as MoveDirection, solver::bounded::solve_level};
+
+        let mut level_progress = LevelProgress::default();
+        level_progress.attempt_status_update(0, LevelStatus::Optimal(solve_level(&LEVELS[0])));
+        level_progress.attempt_status_update(
+            1,
+            LevelStatus::Optimal(
+                [Move::new(Piece::Green, MoveDirection::Up)]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+
+        assert_eq!(level_progress.verify_solutions(), 1);
+        assert!(matches!(
+            level_progress.level_status(0),
+            LevelStatus::Optimal(_)
+        ));
+        assert_eq!(level_progress.level_status(1), LevelStatus::Incomplete);
+    }
+
+    #[test]
+    fn suspended_runs() {
+        use crate::level_run::{Action as RunAction, LevelRun};
+        use crate::solver::bounded::solve_level;
+
+        let mut level_progress = LevelProgress::default();
+        let num_runs = 12;
+        for (idx, level) in LEVELS.iter().enumerate().take(num_runs) {
+            let muv = solve_level(level)[0];
+            let mut level_run = LevelRun::for_test(idx, level);
+            level_run.execute_action(RunAction::SelectPiece(muv.piece));
+            level_run.execute_action(RunAction::Move(muv.direction));
+            level_progress.suspend_run(level_run.suspend().unwrap());
+        }
+
+        // Suspending a level again replaces its run
+        let suspended_run = level_progress.suspended_run(num_runs - 1).unwrap().clone();
+        level_progress.suspend_run(suspended_run);
+
+        #[cfg(feature = "std")]
+        let num_kept = num_runs;
+        #[cfg(not(feature = "std"))]
+        let num_kept = MAX_SUSPENDED_RUNS;
+        assert_eq!(level_progress.suspended_runs.len(), num_kept);
+        for idx in 0..num_runs {
+            assert_eq!(
+                level_progress.suspended_run(idx).is_some(),
+                idx >= num_runs - num_kept
+            );
+        }
+
+        level_progress.clear_suspended_run(num_runs - 1);
+        assert_eq!(level_progress.suspended_run(num_runs - 1), None);
+    }
+
+    #[cfg(not(feature = "unlocked"))]
+    #[test]
+    fn unlocked_levels() {
+        let mut level_progress = LevelProgress::default();
+        assert_eq!(level_progress.num_unlocked_levels(), 10);
+        level_progress
+            .attempt_status_update(0, LevelStatus::Complete(LevelRating::maximum_possible()));
+        assert_eq!(level_progress.num_unlocked_levels(), 11);
+
+        level_progress.set_rules(Rules {
+            num_unlocked: 3,
+            ..Default::default()
+        });
+        assert_eq!(level_progress.num_unlocked_levels(), 4);
+        assert_eq!(level_progress.filtered_indices(Filter::All).count(), 4);
+    }
+
     #[test]
     fn window_vec() {
         let mut window: WindowVec<u8, 16, 5> = WindowVec::default();
@@ -1,7 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(variant_count)]
 #![feature(let_chains)]
-#![feature(generic_const_exprs)]
 
 use core::{
     cmp,
@@ -9,15 +8,21 @@ use core::{
 };
 use enum_map::{Enum, EnumMap};
 use itertools::iproduct;
+use level_run::{Direction, LevelRunState, Move};
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 
+#[cfg(feature = "std")]
+pub mod generator;
 pub mod level_run;
 pub mod level_select;
 pub mod levels;
+#[cfg(feature = "std")]
+pub mod owned_level;
+pub mod solver;
 
 // NOTE: We cannot use a library like `nalgebra` because we need a const constructor.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Vector<T> {
     pub x: T,
     pub y: T,
@@ -62,13 +67,18 @@ impl<T: Ord> Ord for Vector<T> {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, EnumIter, Enum)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, EnumIter, Enum, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum Piece {
     #[default]
     Green = 0,
     Orange = 1,
     Blue = 2,
+    Purple = 3,
+    // Blockers have no goals and only get in the way of the other pieces
+    Grey1 = 4,
+    Grey2 = 5,
+    Grey3 = 6,
 }
 impl TryFrom<u8> for Piece {
     type Error = ();
@@ -78,6 +88,10 @@ impl TryFrom<u8> for Piece {
             0 => Ok(Self::Green),
             1 => Ok(Self::Orange),
             2 => Ok(Self::Blue),
+            3 => Ok(Self::Purple),
+            4 => Ok(Self::Grey1),
+            5 => Ok(Self::Grey2),
+            6 => Ok(Self::Grey3),
             _ => Err(()),
         }
     }
@@ -88,9 +102,39 @@ impl Piece {
             'G' => Some(Self::Green),
             'O' => Some(Self::Orange),
             'B' => Some(Self::Blue),
+            'P' => Some(Self::Purple),
+            'X' => Some(Self::Grey1),
+            'Y' => Some(Self::Grey2),
+            'Z' => Some(Self::Grey3),
             _ => None,
         }
     }
+
+    pub const fn is_blocker(&self) -> bool {
+        matches!(self, Self::Grey1 | Self::Grey2 | Self::Grey3)
+    }
+
+    // The pieces that have goals, in order
+    pub fn colored() -> impl Iterator<Item = Piece> {
+        Self::iter().filter(|p| !p.is_blocker())
+    }
+
+    pub fn blockers() -> impl Iterator<Item = Piece> {
+        Self::iter().filter(|p| p.is_blocker())
+    }
+
+    // Lowercase letters mark starting positions in the text level format
+    pub const fn from_start_char(c: char) -> Option<Self> {
+        if c.is_ascii_lowercase() {
+            Self::from_char(c.to_ascii_uppercase())
+        } else {
+            None
+        }
+    }
+
+    pub fn start_char(&self) -> char {
+        char::from(*self).to_ascii_lowercase()
+    }
 }
 impl From<Piece> for char {
     fn from(value: Piece) -> Self {
@@ -98,6 +142,10 @@ impl From<Piece> for char {
             Piece::Green => 'G',
             Piece::Orange => 'O',
             Piece::Blue => 'B',
+            Piece::Purple => 'P',
+            Piece::Grey1 => 'X',
+            Piece::Grey2 => 'Y',
+            Piece::Grey3 => 'Z',
         }
     }
 }
@@ -107,14 +155,17 @@ impl core::fmt::Display for Piece {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PieceMap<T> {
     num_pieces: usize,
+    num_blockers: usize,
     map: EnumMap<Piece, T>,
 }
 impl<T> PieceMap<T> {
     pub fn pieces(&self) -> impl Iterator<Item = Piece> {
-        Piece::iter().take(self.num_pieces)
+        Piece::colored()
+            .take(self.num_pieces)
+            .chain(Piece::blockers().take(self.num_blockers))
     }
 }
 impl<T> Index<Piece> for PieceMap<T> {
@@ -129,13 +180,15 @@ impl<T> IndexMut<Piece> for PieceMap<T> {
         self.map.index_mut(index)
     }
 }
-impl<T: Clone + Default> From<&[T]> for PieceMap<T> {
-    fn from(value: &[T]) -> Self {
+impl From<StartingPositions<'_>> for PieceMap<Vector<u8>> {
+    fn from(value: StartingPositions<'_>) -> Self {
         Self {
-            num_pieces: value.len(),
-            map: Piece::iter()
-                .zip(value.iter())
-                .map(|(k, v)| (k, v.clone()))
+            num_pieces: value.pieces.len(),
+            num_blockers: value.blockers.len(),
+            map: Piece::colored()
+                .zip(value.pieces.iter())
+                .chain(Piece::blockers().zip(value.blockers.iter()))
+                .map(|(k, v)| (k, *v))
                 .collect(),
         }
     }
@@ -147,7 +200,16 @@ pub enum Space {
     Void,
     Wall,
     Free,
+    // A level can have more than one goal for the same piece, any of which will do
     Goal(Piece),
+    // A goal that any colored piece can finish on
+    AnyGoal,
+    // Stops a piece that slides onto it
+    Stop,
+    // Can only be entered by a piece moving in this direction, otherwise acting as a wall
+    OneWay(Direction),
+    // A piece that slides onto it comes out of the other teleporter with the same number
+    Teleport(u8),
 }
 impl Space {
     pub const fn from_char(c: char) -> Option<Self> {
@@ -155,12 +217,40 @@ impl Space {
             '_' => Some(Self::Void),
             '#' => Some(Self::Wall),
             ' ' => Some(Self::Free),
+            '*' => Some(Self::Stop),
+            '+' => Some(Self::AnyGoal),
+            '^' => Some(Self::OneWay(Direction::Up)),
+            'v' => Some(Self::OneWay(Direction::Down)),
+            '<' => Some(Self::OneWay(Direction::Left)),
+            '>' => Some(Self::OneWay(Direction::Right)),
+            '0'..='9' => Some(Self::Teleport(c as u8 - b'0')),
             _ => match Piece::from_char(c) {
-                Some(p) => Some(Self::Goal(p)),
-                None => None,
+                Some(p) if !p.is_blocker() => Some(Self::Goal(p)),
+                _ => None,
             },
         }
     }
+
+    // Whether a piece can ever be on the space
+    pub const fn is_playable(&self) -> bool {
+        matches!(
+            self,
+            Self::Free
+                | Self::Goal(_)
+                | Self::AnyGoal
+                | Self::Stop
+                | Self::OneWay(_)
+                | Self::Teleport(_)
+        )
+    }
+
+    pub fn is_goal_for(&self, piece: Piece) -> bool {
+        match self {
+            Self::Goal(p) => *p == piece,
+            Self::AnyGoal => true,
+            _ => false,
+        }
+    }
 }
 impl From<Space> for char {
     fn from(value: Space) -> Self {
@@ -169,6 +259,13 @@ impl From<Space> for char {
             Space::Wall => '#',
             Space::Free => ' ',
             Space::Goal(piece) => piece.into(),
+            Space::Stop => '*',
+            Space::AnyGoal => '+',
+            Space::OneWay(Direction::Up) => '^',
+            Space::OneWay(Direction::Down) => 'v',
+            Space::OneWay(Direction::Left) => '<',
+            Space::OneWay(Direction::Right) => '>',
+            Space::Teleport(num) => (b'0' + num) as char,
         }
     }
 }
@@ -178,20 +275,40 @@ impl core::fmt::Display for Space {
     }
 }
 
-#[derive(Debug)]
-pub struct Level {
+// Where the pieces start, with the colored pieces in order followed by any blockers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartingPositions<'a> {
+    pub pieces: &'a [Vector<u8>],
+    pub blockers: &'a [Vector<u8>],
+}
+impl StartingPositions<'_> {
+    pub fn iter(&self) -> impl Iterator<Item = &Vector<u8>> {
+        self.pieces.iter().chain(self.blockers.iter())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Level<'a> {
     pub size: Vector<u8>,
-    spaces: &'static [Space],
-    pub starting_positions: &'static [Vector<u8>],
+    spaces: &'a [Space],
+    pub starting_positions: StartingPositions<'a>,
     pub optimal_moves: u8,
 }
-impl Level {
+impl Level<'_> {
+    // The number of colored pieces, not counting any blockers
     pub fn num_pieces(&self) -> u8 {
-        self.starting_positions.len() as u8
+        self.starting_positions.pieces.len() as u8
     }
 
+    // The pieces that must reach their goals
+    pub fn goal_pieces(&self) -> impl Iterator<Item = Piece> {
+        Piece::colored().take(self.num_pieces() as usize)
+    }
+
+    // Every piece that can be moved, including blockers
     pub fn all_pieces(&self) -> impl Iterator<Item = Piece> {
-        Piece::iter().take(self.num_pieces() as usize)
+        self.goal_pieces()
+            .chain(Piece::blockers().take(self.starting_positions.blockers.len()))
     }
 
     pub fn user_size(&self) -> Vector<u8> {
@@ -205,18 +322,97 @@ impl Level {
     pub fn all_positions(&self) -> impl Iterator<Item = Vector<u8>> {
         iproduct!(0..self.size.y, 0..self.size.x).map(|(y, x)| Vector::new(x, y))
     }
+
+    // Finds the other teleporter of the pair if there is one at the position
+    pub fn teleport_partner(&self, position: Vector<u8>) -> Option<Vector<u8>> {
+        let space @ Space::Teleport(_) = self.get_space(position) else {
+            return None;
+        };
+
+        self.all_positions()
+            .find(|p| *p != position && self.get_space(*p) == space)
+    }
+
+    // Replays the moves from the starting positions and returns the number of moves if they win
+    pub fn verify_solution(&self, moves: &[Move]) -> Result<u8, VerifyError> {
+        let num_moves = u8::try_from(moves.len()).map_err(|_| VerifyError::TooLong)?;
+        let mut state = LevelRunState::from(self);
+
+        for (idx, muv) in moves.iter().enumerate() {
+            if !self.all_pieces().any(|p| p == muv.piece) || state.attempt_move(*muv).is_none() {
+                return Err(VerifyError::IllegalMove(idx));
+            }
+        }
+
+        if state.is_winning() {
+            Ok(num_moves)
+        } else {
+            Err(VerifyError::NotWinning)
+        }
+    }
+}
+
+// Formats in the text format, which is the space characters with the starting position of each piece
+// shown as a lowercase letter, with blockers being x, y and z. Below a blank line, any spaces other
+// than free ones with pieces on them and the optimal number of moves follow, for example:
+//
+// #####
+// #go #
+// #   #
+// #OG #
+// #####
+//
+// optimal: 5
+impl core::fmt::Display for Level<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let starting_pieces = || self.all_pieces().zip(self.starting_positions.iter());
+
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                let position = Vector::new(x, y);
+
+                match starting_pieces().find(|(_, p)| **p == position) {
+                    Some((piece, _)) => write!(f, "{}", piece.start_char())?,
+                    None => write!(f, "{}", self.get_space(position))?,
+                }
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)?;
+
+        for (piece, position) in starting_pieces() {
+            let space = self.get_space(*position);
+            if space != Space::Free {
+                writeln!(f, "{} on {}", piece.start_char(), space)?;
+            }
+        }
+        write!(f, "optimal: {}", self.optimal_moves)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyError {
+    // The move at this index could not be made
+    IllegalMove(usize),
+    // All moves could be made but the level was not won
+    NotWinning,
+    // There are more moves than can be counted
+    TooLong,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct LevelRating(u8);
 impl LevelRating {
-    pub fn new(goal: u8, num_moves: u8) -> Self {
+    pub fn new(goal: u8, num_moves: u16, rules: &Rules) -> Self {
         let max = Self::maximum_possible().num_stars();
+        let extra_moves = num_moves.saturating_sub(goal as u16);
+        let grace_moves = rules.grace_moves as u16;
 
-        Self(if num_moves <= goal {
+        // Stars are lost evenly over the grace moves, leaving at least two until they run out
+        Self(if extra_moves == 0 {
             max
-        } else if num_moves <= goal + 3 {
-            max - (num_moves - goal)
+        } else if extra_moves <= grace_moves {
+            max - (extra_moves * (max as u16 - 2)).div_ceil(grace_moves) as u8
         } else {
             1
         })
@@ -248,6 +444,26 @@ impl Default for LevelRating {
     }
 }
 
+// Parts of the game that players may want to change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    // Whether another piece is moved and made active when the active piece cannot move
+    pub auto_switch: bool,
+    // Number of moves over the optimal number that still earn more than one star
+    pub grace_moves: u8,
+    // Number of levels that can be played beyond those that have been completed
+    pub num_unlocked: usize,
+}
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            auto_switch: true,
+            grace_moves: 3,
+            num_unlocked: 10,
+        }
+    }
+}
+
 pub trait BufferedRenderer {
     fn flush(&mut self);
 }
@@ -259,12 +475,62 @@ mod tests {
     #[test]
     fn level_rating() {
         let goal = 6;
-        assert_eq!(LevelRating::new(goal, goal).num_stars(), 5);
-        assert_eq!(LevelRating::new(goal, goal + 1).num_stars(), 4);
-        assert_eq!(LevelRating::new(goal, goal + 2).num_stars(), 3);
-        assert_eq!(LevelRating::new(goal, goal + 3).num_stars(), 2);
-        assert_eq!(LevelRating::new(goal, goal + 4).num_stars(), 1);
-        assert_eq!(LevelRating::new(goal, goal + 50).num_stars(), 1);
-        assert_eq!(LevelRating::new(goal, u8::MAX).num_stars(), 1);
+        let rules = Rules::default();
+        assert_eq!(LevelRating::new(goal, 5, &rules).num_stars(), 5);
+        assert_eq!(LevelRating::new(goal, 6, &rules).num_stars(), 5);
+        assert_eq!(LevelRating::new(goal, 7, &rules).num_stars(), 4);
+        assert_eq!(LevelRating::new(goal, 8, &rules).num_stars(), 3);
+        assert_eq!(LevelRating::new(goal, 9, &rules).num_stars(), 2);
+        assert_eq!(LevelRating::new(goal, 10, &rules).num_stars(), 1);
+        assert_eq!(LevelRating::new(goal, 56, &rules).num_stars(), 1);
+        assert_eq!(LevelRating::new(goal, 300, &rules).num_stars(), 1);
+        assert_eq!(LevelRating::new(goal, u16::MAX, &rules).num_stars(), 1);
+
+        // Stricter and more lenient ratings
+        let rules = Rules {
+            grace_moves: 0,
+            ..Default::default()
+        };
+        assert_eq!(LevelRating::new(goal, 6, &rules).num_stars(), 5);
+        assert_eq!(LevelRating::new(goal, 7, &rules).num_stars(), 1);
+        let rules = Rules {
+            grace_moves: 6,
+            ..Default::default()
+        };
+        assert_eq!(LevelRating::new(goal, 7, &rules).num_stars(), 4);
+        assert_eq!(LevelRating::new(goal, 9, &rules).num_stars(), 3);
+        assert_eq!(LevelRating::new(goal, 12, &rules).num_stars(), 2);
+        assert_eq!(LevelRating::new(goal, 13, &rules).num_stars(), 1);
+    }
+
+    #[test]
+    fn verify_solution() {
+        use level_run::Direction;
+        use levels::LEVELS;
+        use solver::bounded::solve_level;
+
+        let level = &LEVELS[0];
+        let moves = solve_level(level);
+
+        assert_eq!(level.verify_solution(&moves), Ok(level.optimal_moves));
+        assert_eq!(
+            level.verify_solution(&moves[..moves.len() - 1]),
+            Err(VerifyError::NotWinning)
+        );
+        assert_eq!(level.verify_solution(&[]), Err(VerifyError::NotWinning));
+
+        // A piece cannot slide again in the same direction, and there is no blue piece
+        assert_eq!(
+            level.verify_solution(&[moves[0], moves[0]]),
+            Err(VerifyError::IllegalMove(1))
+        );
+        assert_eq!(
+            level.verify_solution(&[Move::new(Piece::Blue, Direction::Down)]),
+            Err(VerifyError::IllegalMove(0))
+        );
+        assert_eq!(
+            level.verify_solution(&[moves[0]; u8::MAX as usize + 1]),
+            Err(VerifyError::TooLong)
+        );
     }
 }
@@ -0,0 +1,599 @@
+use crate::{
+    Level, Piece, Space, StartingPositions, Vector,
+    levels::{MAX_LEVEL_SIZE, MAX_OPTIMAL_MOVES},
+    solver::solve,
+};
+use core::str::FromStr;
+use enum_map::EnumMap;
+use itertools::{Itertools, iproduct};
+use serde::{Deserialize, Serialize};
+use std::{fs::File, io::BufReader, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelError {
+    NoRows,
+    // The row at this index is not the same length as the first row
+    RaggedRow(usize),
+    TooLarge,
+    InvalidCharacter(char),
+    // A playable space is next to the edge of the level or a void space
+    NotEnclosed(Vector<u8>),
+    InvalidNumPieces,
+    TooManyBlockers,
+    InvalidPosition(Vector<u8>),
+    DuplicatePiece(Piece),
+    // A piece is in the level without all the pieces before it
+    MissingPiece(Piece),
+    // The line with this number after the rows could not be understood
+    InvalidLine(usize),
+    // There is not exactly one other teleporter with this number
+    UnpairedTeleporter(u8),
+    // More moves are needed than an optimal solution can hold
+    TooManyMoves,
+    Unsolvable,
+}
+impl core::fmt::Display for LevelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LevelError::NoRows => write!(f, "the level has no rows"),
+            LevelError::RaggedRow(idx) => {
+                write!(f, "row {} is not the same length as the first row", idx + 1)
+            }
+            LevelError::TooLarge => write!(
+                f,
+                "the level is larger than {MAX_LEVEL_SIZE}x{MAX_LEVEL_SIZE}"
+            ),
+            LevelError::InvalidCharacter(c) => write!(f, "invalid character '{c}' for a space"),
+            LevelError::NotEnclosed(p) => {
+                write!(
+                    f,
+                    "the space at ({}, {}) is not enclosed by walls",
+                    p.x, p.y
+                )
+            }
+            LevelError::InvalidNumPieces => write!(
+                f,
+                "there must be between 1 and {} pieces",
+                Piece::colored().count()
+            ),
+            LevelError::TooManyBlockers => {
+                write!(
+                    f,
+                    "there can be at most {} blockers",
+                    Piece::blockers().count()
+                )
+            }
+            LevelError::InvalidPosition(p) => write!(
+                f,
+                "the starting position ({}, {}) is not a free space or is shared",
+                p.x, p.y
+            ),
+            LevelError::DuplicatePiece(piece) => write!(f, "the {piece:?} piece appears twice"),
+            LevelError::MissingPiece(piece) => write!(
+                f,
+                "the {piece:?} piece is needed since pieces after it are in the level"
+            ),
+            LevelError::InvalidLine(num) => write!(f, "line {num} is not valid"),
+            LevelError::UnpairedTeleporter(num) => {
+                write!(f, "teleporter {num} does not appear exactly twice")
+            }
+            LevelError::TooManyMoves => write!(
+                f,
+                "the optimal number of moves is more than {MAX_OPTIMAL_MOVES}"
+            ),
+            LevelError::Unsolvable => write!(f, "the level cannot be solved"),
+        }
+    }
+}
+impl std::error::Error for LevelError {}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Level(LevelError),
+}
+impl core::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LoadError::Io(error) => error.fmt(f),
+            LoadError::Json(error) => error.fmt(f),
+            LoadError::Level(error) => error.fmt(f),
+        }
+    }
+}
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(error) => Some(error),
+            LoadError::Json(error) => Some(error),
+            LoadError::Level(error) => Some(error),
+        }
+    }
+}
+impl From<std::io::Error> for LoadError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+impl From<serde_json::Error> for LoadError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+impl From<LevelError> for LoadError {
+    fn from(value: LevelError) -> Self {
+        Self::Level(value)
+    }
+}
+
+// A level that owns its data so that it can be created or loaded at runtime, unlike the built-in ones.
+//
+// It can only be made through `new` or by parsing, so that it is always playable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "LevelData", into = "LevelData")]
+pub struct OwnedLevel {
+    size: Vector<u8>,
+    spaces: Vec<Space>,
+    starting_positions: Vec<Vector<u8>>,
+    blocker_positions: Vec<Vector<u8>>,
+    optimal_moves: u8,
+}
+impl OwnedLevel {
+    // Builds a level from rows of space characters, as in the level data file, checking that it is playable
+    pub fn new(
+        rows: &[impl AsRef<str>],
+        starting_positions: Vec<Vector<u8>>,
+        blocker_positions: Vec<Vector<u8>>,
+        optimal_moves: u8,
+    ) -> Result<Self, LevelError> {
+        let width = rows
+            .first()
+            .ok_or(LevelError::NoRows)?
+            .as_ref()
+            .chars()
+            .count();
+        if let Some(idx) = rows
+            .iter()
+            .position(|r| r.as_ref().chars().count() != width)
+        {
+            return Err(LevelError::RaggedRow(idx));
+        }
+        if width > MAX_LEVEL_SIZE || rows.len() > MAX_LEVEL_SIZE {
+            return Err(LevelError::TooLarge);
+        }
+
+        let level = Self {
+            size: Vector::new(width as u8, rows.len() as u8),
+            spaces: rows
+                .iter()
+                .flat_map(|r| r.as_ref().chars())
+                .map(|c| Space::from_char(c).ok_or(LevelError::InvalidCharacter(c)))
+                .try_collect()?,
+            starting_positions,
+            blocker_positions,
+            optimal_moves,
+        };
+        level.validate()?;
+
+        Ok(level)
+    }
+
+    fn validate(&self) -> Result<(), LevelError> {
+        let level = self.level();
+        let is_next_to_void = |p: Vector<u8>| {
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .into_iter()
+                .any(|(x, y)| level.get_space(p + Vector::new(x, y)) == Space::Void)
+        };
+
+        // Pieces can only be stopped by walls, so must never be able to slide off the level
+        for position in level.all_positions() {
+            if level.get_space(position).is_playable()
+                && (position.x == 0
+                    || position.y == 0
+                    || position.x == self.size.x - 1
+                    || position.y == self.size.y - 1
+                    || is_next_to_void(position))
+            {
+                return Err(LevelError::NotEnclosed(position));
+            }
+        }
+
+        if let Some((_, num)) = self
+            .spaces
+            .iter()
+            .filter_map(|s| match s {
+                Space::Teleport(num) => Some(*num),
+                _ => None,
+            })
+            .sorted()
+            .dedup_with_count()
+            .find(|(count, _)| *count != 2)
+        {
+            return Err(LevelError::UnpairedTeleporter(num));
+        }
+
+        if self.optimal_moves as usize > MAX_OPTIMAL_MOVES {
+            return Err(LevelError::TooManyMoves);
+        }
+
+        if self.starting_positions.is_empty()
+            || self.starting_positions.len() > Piece::colored().count()
+        {
+            return Err(LevelError::InvalidNumPieces);
+        }
+        if self.blocker_positions.len() > Piece::blockers().count() {
+            return Err(LevelError::TooManyBlockers);
+        }
+        let positions = level.starting_positions.iter().collect_vec();
+        for (idx, position) in positions.iter().enumerate() {
+            if position.x >= self.size.x
+                || position.y >= self.size.y
+                || !level.get_space(**position).is_playable()
+                || positions[..idx].contains(position)
+            {
+                return Err(LevelError::InvalidPosition(**position));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Loads a single level in the text format from a `txt` file, or otherwise either a single
+    // level or an array of them from a JSON file
+    pub fn load_file(path: &Path) -> Result<Vec<Self>, LoadError> {
+        if path.extension().is_some_and(|e| e == "txt") {
+            return Ok(vec![std::fs::read_to_string(path)?.parse()?]);
+        }
+
+        let value: serde_json::Value = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        Ok(if value.is_array() {
+            serde_json::from_value(value)?
+        } else {
+            vec![serde_json::from_value(value)?]
+        })
+    }
+
+    pub fn level(&self) -> Level<'_> {
+        Level {
+            size: self.size,
+            spaces: &self.spaces,
+            starting_positions: StartingPositions {
+                pieces: &self.starting_positions,
+                blockers: &self.blocker_positions,
+            },
+            optimal_moves: self.optimal_moves,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = String> + '_ {
+        self.spaces
+            .chunks(self.size.x as usize)
+            .map(|row| row.iter().map(|s| char::from(*s)).collect())
+    }
+}
+impl core::fmt::Display for OwnedLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.level().fmt(f)
+    }
+}
+// Parses the text format described on the `Level` display implementation.
+//
+// If the optimal number of moves is not given then the level is solved to find it.
+impl FromStr for OwnedLevel {
+    type Err = LevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .skip_while(|(_, line)| line.trim().is_empty());
+
+        // Separate the pieces from the spaces beneath them
+        let mut rows = Vec::new();
+        let mut positions: EnumMap<Piece, Option<Vector<u8>>> = EnumMap::default();
+        for (y, (_, line)) in lines
+            .by_ref()
+            .take_while(|(_, line)| !line.trim().is_empty())
+            .enumerate()
+        {
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                match Piece::from_start_char(c) {
+                    Some(piece) => {
+                        if positions[piece].is_some() {
+                            return Err(LevelError::DuplicatePiece(piece));
+                        }
+                        positions[piece] = Some(Vector::new(x as u8, y as u8));
+                        row.push(char::from(Space::Free));
+                    }
+                    None => row.push(c),
+                }
+            }
+            rows.push(row);
+        }
+
+        let mut optimal_moves = None;
+        for (idx, line) in lines {
+            let line = line.trim();
+            let invalid = LevelError::InvalidLine(idx + 1);
+
+            if line.is_empty() {
+                continue;
+            } else if let Some(optimal) = line.strip_prefix("optimal:") {
+                optimal_moves = Some(optimal.trim().parse().map_err(|_| invalid)?);
+            } else if let Some((piece, space)) = line.split_once(" on ")
+                && let Some(piece) = single_char(piece).and_then(Piece::from_start_char)
+                && let Some(space) = single_char(space).and_then(Space::from_char)
+                && let Some(position) = positions[piece]
+            {
+                rows[position.y as usize][position.x as usize] = space.into();
+            } else {
+                return Err(invalid);
+            }
+        }
+
+        // Pieces and blockers are each in order, so none can be skipped
+        let in_order = |pieces: Vec<Piece>| {
+            let found = pieces.iter().map_while(|p| positions[*p]).collect_vec();
+            if pieces[found.len()..]
+                .iter()
+                .any(|p| positions[*p].is_some())
+            {
+                return Err(LevelError::MissingPiece(pieces[found.len()]));
+            }
+            Ok(found)
+        };
+        let starting_positions = in_order(Piece::colored().collect())?;
+        let blocker_positions = in_order(Piece::blockers().collect())?;
+
+        let rows = rows
+            .into_iter()
+            .map(|r| r.into_iter().collect::<String>())
+            .collect_vec();
+        let mut level = Self::new(
+            &rows,
+            starting_positions,
+            blocker_positions,
+            optimal_moves.unwrap_or(0),
+        )?;
+        if optimal_moves.is_none() {
+            let moves = solve(&level.level()).ok_or(LevelError::Unsolvable)?;
+            if moves.len() > MAX_OPTIMAL_MOVES {
+                return Err(LevelError::TooManyMoves);
+            }
+            level.optimal_moves = moves.len() as u8;
+        }
+
+        Ok(level)
+    }
+}
+
+fn single_char(s: &str) -> Option<char> {
+    s.trim().chars().exactly_one().ok()
+}
+
+// Removes extraneous walls by voiding wall spaces surrounded by other walls (or the edge of the level)
+pub fn void_extraneous_walls(size: Vector<u8>, spaces: &mut [Space]) {
+    let index = |x: u8, y: u8| size.x as usize * y as usize + x as usize;
+
+    let void_positions = iproduct!(0..size.y, 0..size.x)
+        .filter(|(y, x)| {
+            spaces[index(*x, *y)] == Space::Wall
+                && iproduct!(-1i8..=1, -1i8..=1).all(|(dy, dx)| {
+                    let (x, y) = (*x as i8 + dx, *y as i8 + dy);
+
+                    x < 0
+                        || y < 0
+                        || x >= size.x as i8
+                        || y >= size.y as i8
+                        || spaces[index(x as u8, y as u8)] == Space::Wall
+                })
+        })
+        .collect_vec();
+    for (y, x) in void_positions {
+        spaces[index(x, y)] = Space::Void;
+    }
+}
+
+// The serialized form, which is compact and easy to edit by hand
+#[derive(Serialize, Deserialize)]
+struct LevelData {
+    spaces: Vec<String>,
+    positions: Vec<[u8; 2]>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    blockers: Vec<[u8; 2]>,
+    optimal: u8,
+}
+impl TryFrom<LevelData> for OwnedLevel {
+    type Error = LevelError;
+
+    fn try_from(value: LevelData) -> Result<Self, Self::Error> {
+        let vectors = |positions: Vec<[u8; 2]>| {
+            positions
+                .into_iter()
+                .map(|[x, y]| Vector::new(x, y))
+                .collect()
+        };
+
+        Self::new(
+            &value.spaces,
+            vectors(value.positions),
+            vectors(value.blockers),
+            value.optimal,
+        )
+    }
+}
+impl From<OwnedLevel> for LevelData {
+    fn from(value: OwnedLevel) -> Self {
+        let arrays = |positions: &[Vector<u8>]| positions.iter().map(|p| [p.x, p.y]).collect();
+
+        Self {
+            spaces: value.rows().collect(),
+            positions: arrays(&value.starting_positions),
+            blockers: arrays(&value.blocker_positions),
+            optimal: value.optimal_moves,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levels::LEVELS;
+    use std::slice;
+
+    #[test]
+    fn new() {
+        let level = OwnedLevel::new(
+            &["#####", "#   #", "#   #", "#OG #", "#####"],
+            vec![Vector::new(1, 1), Vector::new(2, 1)],
+            Vec::new(),
+            5,
+        )
+        .unwrap();
+        assert_eq!(level.level().spaces, LEVELS[0].spaces);
+        assert_eq!(level.rows().collect_vec()[3], "#OG #");
+
+        let positions = vec![Vector::new(1, 1)];
+        assert_eq!(
+            OwnedLevel::new(&["###", "# #", "##"], positions.clone(), Vec::new(), 1),
+            Err(LevelError::RaggedRow(2))
+        );
+        assert_eq!(
+            OwnedLevel::new(&["###", "#x#", "###"], positions.clone(), Vec::new(), 1),
+            Err(LevelError::InvalidCharacter('x'))
+        );
+        assert_eq!(
+            OwnedLevel::new(&["###", "# _", "###"], positions.clone(), Vec::new(), 1),
+            Err(LevelError::NotEnclosed(Vector::new(1, 1)))
+        );
+        assert_eq!(
+            OwnedLevel::new(&["###", "# #", "###"], Vec::new(), Vec::new(), 1),
+            Err(LevelError::InvalidNumPieces)
+        );
+        assert_eq!(
+            OwnedLevel::new(
+                &["###", "# #", "###"],
+                vec![Vector::new(0, 1)],
+                Vec::new(),
+                1
+            ),
+            Err(LevelError::InvalidPosition(Vector::new(0, 1)))
+        );
+        assert_eq!(
+            OwnedLevel::new(
+                &["#####", "# 1 #", "#####"],
+                positions.clone(),
+                Vec::new(),
+                1
+            ),
+            Err(LevelError::UnpairedTeleporter(1))
+        );
+        assert_eq!(
+            OwnedLevel::new(&["####", "#  #", "####"], positions.clone(), positions, 1),
+            Err(LevelError::InvalidPosition(Vector::new(1, 1)))
+        );
+        assert_eq!(
+            OwnedLevel::new(
+                &["###", "# #", "###"],
+                vec![Vector::new(1, 1)],
+                Vec::new(),
+                MAX_OPTIMAL_MOVES as u8 + 1
+            ),
+            Err(LevelError::TooManyMoves)
+        );
+    }
+
+    #[test]
+    fn text_format() {
+        for level in LEVELS.iter() {
+            let parsed: OwnedLevel = level.to_string().parse().unwrap();
+            assert_eq!(parsed.level(), *level);
+        }
+
+        // Pieces can start on goals and the optimal number of moves can be found
+        let level: OwnedLevel = "
+####
+#b #
+# o#
+#gB#
+####
+
+o on G
+b on O
+"
+        .parse()
+        .unwrap();
+        assert_eq!(
+            level.rows().collect_vec(),
+            ["####", "#O #", "# G#", "# B#", "####"]
+        );
+        assert_eq!(
+            level.starting_positions,
+            [Vector::new(1, 3), Vector::new(2, 2), Vector::new(1, 1)]
+        );
+        assert_eq!(level.optimal_moves, 6);
+        assert_eq!(level.to_string().parse(), Ok(level));
+
+        assert_eq!(
+            "###\n#g#\n##".parse::<OwnedLevel>(),
+            Err(LevelError::RaggedRow(2))
+        );
+        assert_eq!(
+            "###\n#g#\n#?#\n###".parse::<OwnedLevel>(),
+            Err(LevelError::InvalidCharacter('?'))
+        );
+        assert_eq!(
+            "#####\n#g g#\n#####".parse::<OwnedLevel>(),
+            Err(LevelError::DuplicatePiece(Piece::Green))
+        );
+        assert_eq!(
+            "#####\n#g b#\n#####".parse::<OwnedLevel>(),
+            Err(LevelError::MissingPiece(Piece::Orange))
+        );
+        assert_eq!(
+            "####\n#gG#\n####\n\no on G".parse::<OwnedLevel>(),
+            Err(LevelError::InvalidLine(5))
+        );
+        assert_eq!(
+            "####\n#gO#\n####".parse::<OwnedLevel>(),
+            Err(LevelError::Unsolvable)
+        );
+    }
+
+    #[test]
+    fn load_file() {
+        let dir = std::env::temp_dir().join(format!("kuboble-load-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let level: OwnedLevel = LEVELS[0].to_string().parse().unwrap();
+
+        let path = dir.join("level.txt");
+        std::fs::write(&path, level.to_string()).unwrap();
+        assert_eq!(
+            OwnedLevel::load_file(&path).unwrap(),
+            slice::from_ref(&level)
+        );
+
+        // JSON files can have one level or several
+        let path = dir.join("level.json");
+        std::fs::write(&path, serde_json::to_string(&level).unwrap()).unwrap();
+        assert_eq!(
+            OwnedLevel::load_file(&path).unwrap(),
+            slice::from_ref(&level)
+        );
+        let path = dir.join("levels.json");
+        std::fs::write(&path, serde_json::to_string(&[&level, &level]).unwrap()).unwrap();
+        assert_eq!(
+            OwnedLevel::load_file(&path).unwrap(),
+            [level.clone(), level]
+        );
+
+        assert!(matches!(
+            OwnedLevel::load_file(&dir.join("missing.json")),
+            Err(LoadError::Io(_))
+        ));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
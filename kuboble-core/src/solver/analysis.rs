@@ -0,0 +1,142 @@
+use super::{Positions, all_moves};
+use crate::{Level, level_run::LevelRunState};
+use std::collections::{HashMap, VecDeque, hash_map::Entry};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelAnalysis {
+    // Number of distinct positions that can be reached from the start
+    pub num_states: usize,
+    // Average number of legal moves from each non-winning position
+    pub average_branching: f64,
+    // Number of positions from which the level can no longer be won
+    pub num_dead_ends: usize,
+    // Number of distinct move sequences that win the level in the optimal number of moves
+    pub num_optimal_solutions: u64,
+    pub optimal_moves: Option<u8>,
+}
+
+struct Node {
+    positions: Positions,
+    distance: u8,
+    is_winning: bool,
+    successors: Vec<usize>,
+}
+
+// Explores the entire graph of positions that can be reached from the starting positions.
+//
+// Winning positions are not expanded since the level ends once one is reached.
+pub fn analyze(level: &Level) -> LevelAnalysis {
+    let start = LevelRunState::from(level);
+
+    let mut nodes = vec![Node {
+        positions: start.positions.clone(),
+        distance: 0,
+        is_winning: start.is_winning(),
+        successors: Vec::new(),
+    }];
+    let mut indices = HashMap::from([(start.positions.clone(), 0)]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(idx) = queue.pop_front() {
+        if nodes[idx].is_winning {
+            continue;
+        }
+
+        let state = LevelRunState {
+            level,
+            positions: nodes[idx].positions.clone(),
+        };
+        for muv in all_moves(level) {
+            let mut new_state = state.clone();
+
+            if new_state.attempt_move(muv).is_some() {
+                let new_idx = match indices.entry(new_state.positions.clone()) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        nodes.push(Node {
+                            is_winning: new_state.is_winning(),
+                            positions: new_state.positions,
+                            distance: nodes[idx].distance + 1,
+                            successors: Vec::new(),
+                        });
+                        queue.push_back(nodes.len() - 1);
+                        *entry.insert(nodes.len() - 1)
+                    }
+                };
+
+                nodes[idx].successors.push(new_idx);
+            }
+        }
+    }
+
+    // Work backwards from the winning positions to find those that can still win
+    let mut predecessors = vec![Vec::new(); nodes.len()];
+    for (idx, node) in nodes.iter().enumerate() {
+        for successor in node.successors.iter() {
+            predecessors[*successor].push(idx);
+        }
+    }
+    let mut can_win: Vec<bool> = nodes.iter().map(|n| n.is_winning).collect();
+    let mut queue: VecDeque<usize> = (0..nodes.len()).filter(|idx| can_win[*idx]).collect();
+    while let Some(idx) = queue.pop_front() {
+        for predecessor in predecessors[idx].iter() {
+            if !can_win[*predecessor] {
+                can_win[*predecessor] = true;
+                queue.push_back(*predecessor);
+            }
+        }
+    }
+
+    // Count the shortest paths to each position, noting that nodes are in order of distance
+    let mut num_paths = vec![0u64; nodes.len()];
+    num_paths[0] = 1;
+    for (idx, node) in nodes.iter().enumerate() {
+        for successor in node.successors.iter() {
+            if nodes[*successor].distance == node.distance + 1 {
+                num_paths[*successor] = num_paths[*successor].saturating_add(num_paths[idx]);
+            }
+        }
+    }
+    let optimal_moves = nodes.iter().find(|n| n.is_winning).map(|n| n.distance);
+
+    let num_expanded = nodes.iter().filter(|n| !n.is_winning).count();
+    LevelAnalysis {
+        num_states: nodes.len(),
+        average_branching: if num_expanded > 0 {
+            nodes.iter().map(|n| n.successors.len()).sum::<usize>() as f64 / num_expanded as f64
+        } else {
+            0.0
+        },
+        num_dead_ends: can_win.iter().filter(|w| !**w).count(),
+        num_optimal_solutions: optimal_moves.map_or(0, |d| {
+            nodes
+                .iter()
+                .zip(num_paths.iter())
+                .filter(|(n, _)| n.is_winning && n.distance == d)
+                .map(|(_, p)| *p)
+                .fold(0, u64::saturating_add)
+        }),
+        optimal_moves,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levels::LEVELS;
+
+    #[test]
+    fn analyze_levels() {
+        for level in LEVELS.iter().take(20) {
+            let analysis = analyze(level);
+
+            assert_eq!(analysis.optimal_moves, Some(level.optimal_moves));
+            assert!(analysis.num_optimal_solutions >= 1);
+            assert!(analysis.num_dead_ends < analysis.num_states);
+            assert!(analysis.average_branching > 0.0);
+        }
+
+        // Some levels can get stuck
+        assert!(analyze(&LEVELS[31]).num_dead_ends > 0);
+    }
+}
@@ -0,0 +1,255 @@
+use super::{Positions, all_moves};
+#[cfg(test)]
+use crate::levels::MAX_OPTIMAL_MOVES;
+use crate::{
+    Level, Piece, Space,
+    level_run::{LevelRunState, Move},
+    levels::MAX_LEVEL_SIZE,
+};
+use arrayvec::ArrayVec;
+use enum_map::EnumMap;
+
+// The goal lines are stored as bit masks
+const _: () = assert!(MAX_LEVEL_SIZE <= u32::BITS as usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundedSolution<const D: usize> {
+    Solved(ArrayVec<Move, D>),
+    // There is no solution at all
+    Unsolvable,
+    // There is no solution with `D` or fewer moves, though there may be a longer one
+    TooLong,
+    // The node limit was reached before a solution was found
+    GaveUp,
+}
+
+// Rows and columns that contain at least one goal for a piece
+#[derive(Debug, Default, Clone, Copy)]
+struct GoalLines {
+    rows: u32,
+    columns: u32,
+}
+
+enum SearchResult {
+    Found,
+    GaveUp,
+    // The smallest cost estimate that exceeded the bound, if any
+    Exceeded(Option<u8>),
+}
+
+// Iterative deepening A* search that uses a fixed amount of memory, suitable for `no_std`.
+//
+// The solution can be at most `D` moves long, and no more than `node_limit` positions will be expanded.
+struct BoundedSolver<'a, const D: usize> {
+    level: &'a Level<'a>,
+    goal_lines: EnumMap<Piece, GoalLines>,
+    path: ArrayVec<Move, D>,
+    // Positions along the current path, used to avoid going in circles
+    visited: ArrayVec<Positions, D>,
+    num_nodes: u32,
+    node_limit: u32,
+}
+impl<const D: usize> BoundedSolver<'_, D> {
+    // Lower bound on the number of moves still needed, which never overestimates.
+    //
+    // Each piece that is off its goals needs at least one move, or two if it is not lined up with any.
+    // Sliding through a teleporter can change both the row and column in one move, so every line
+    // counts as lined up on levels that have them.
+    fn estimate(&self, state: &LevelRunState) -> u8 {
+        self.level
+            .goal_pieces()
+            .map(|piece| {
+                let (position, lines) = (state.piece_position(piece), self.goal_lines[piece]);
+
+                if self.level.get_space(position).is_goal_for(piece) {
+                    0
+                } else if lines.rows & (1 << position.y) != 0
+                    || lines.columns & (1 << position.x) != 0
+                {
+                    1
+                } else {
+                    2
+                }
+            })
+            .sum()
+    }
+
+    fn search(&mut self, state: &LevelRunState, bound: u8) -> SearchResult {
+        let cost = self.path.len() as u8 + self.estimate(state);
+        if cost > bound {
+            return SearchResult::Exceeded(Some(cost));
+        }
+        if state.is_winning() {
+            return SearchResult::Found;
+        }
+
+        self.num_nodes += 1;
+        if self.num_nodes > self.node_limit {
+            return SearchResult::GaveUp;
+        }
+
+        let mut min_exceeded = None;
+        for muv in all_moves(self.level) {
+            let mut new_state = state.clone();
+
+            if new_state.attempt_move(muv).is_none() || self.visited.contains(&new_state.positions)
+            {
+                continue;
+            }
+
+            // Since the estimate is at least one here, these cannot overflow.
+            self.path.push(muv);
+            self.visited.push(new_state.positions.clone());
+
+            match self.search(&new_state, bound) {
+                SearchResult::Found => return SearchResult::Found,
+                SearchResult::GaveUp => return SearchResult::GaveUp,
+                SearchResult::Exceeded(c) => {
+                    min_exceeded = match (min_exceeded, c) {
+                        (Some(m), Some(c)) => Some(c.min(m)),
+                        (m, c) => m.or(c),
+                    };
+                }
+            }
+
+            self.path.pop();
+            self.visited.pop();
+        }
+
+        SearchResult::Exceeded(min_exceeded)
+    }
+}
+
+// Finds a shortest sequence of moves that wins the level from arbitrary piece positions, using
+// bounded memory and time.
+pub fn solve_bounded<const D: usize>(
+    level: &Level,
+    positions: &Positions,
+    node_limit: u32,
+) -> BoundedSolution<D> {
+    let state = LevelRunState {
+        level,
+        positions: positions.clone(),
+    };
+
+    let mut goal_lines: EnumMap<Piece, GoalLines> = EnumMap::default();
+    if level
+        .all_positions()
+        .any(|p| matches!(level.get_space(p), Space::Teleport(_)))
+    {
+        for piece in level.goal_pieces() {
+            goal_lines[piece] = GoalLines {
+                rows: u32::MAX,
+                columns: u32::MAX,
+            };
+        }
+    } else {
+        for position in level.all_positions() {
+            let space = level.get_space(position);
+
+            for piece in level.goal_pieces().filter(|p| space.is_goal_for(*p)) {
+                goal_lines[piece].rows |= 1 << position.y;
+                goal_lines[piece].columns |= 1 << position.x;
+            }
+        }
+    }
+
+    let mut solver = BoundedSolver {
+        level,
+        goal_lines,
+        path: ArrayVec::new(),
+        visited: ArrayVec::new(),
+        num_nodes: 0,
+        node_limit,
+    };
+
+    let mut bound = solver.estimate(&state);
+    loop {
+        if bound as usize > D {
+            break BoundedSolution::TooLong;
+        }
+
+        match solver.search(&state, bound) {
+            SearchResult::Found => break BoundedSolution::Solved(solver.path),
+            SearchResult::GaveUp => break BoundedSolution::GaveUp,
+            SearchResult::Exceeded(Some(c)) => bound = c,
+            // Every path ran into a dead end or went in a circle without reaching the bound
+            SearchResult::Exceeded(None) => break BoundedSolution::Unsolvable,
+        }
+    }
+}
+
+// Solves a level from its starting positions, which tests expect to be possible
+#[cfg(test)]
+pub(crate) fn solve_level(level: &Level) -> ArrayVec<Move, MAX_OPTIMAL_MOVES> {
+    match solve_bounded(level, &level.starting_positions.into(), u32::MAX) {
+        BoundedSolution::Solved(moves) => moves,
+        _ => panic!("level should be solvable"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levels::LEVELS;
+
+    #[test]
+    fn solve_levels() {
+        for level in LEVELS.iter().take(10) {
+            let solution = solve_bounded::<20>(level, &level.starting_positions.into(), u32::MAX);
+
+            match solution {
+                BoundedSolution::Solved(moves) => {
+                    assert_eq!(moves.len(), level.optimal_moves as usize)
+                }
+                _ => panic!("level should be solvable"),
+            }
+        }
+
+        // Not enough moves allowed
+        assert_eq!(
+            solve_bounded::<4>(&LEVELS[0], &LEVELS[0].starting_positions.into(), u32::MAX),
+            BoundedSolution::TooLong
+        );
+
+        // Not enough nodes allowed
+        assert_eq!(
+            solve_bounded::<20>(&LEVELS[9], &LEVELS[9].starting_positions.into(), 10),
+            BoundedSolution::GaveUp
+        );
+
+        // A single slide through the teleporters changes both the row and column
+        #[cfg(feature = "std")]
+        {
+            let level: crate::owned_level::OwnedLevel = "
+#######
+#g 1  #
+#  1 G#
+#######
+"
+            .parse()
+            .unwrap();
+            let level = level.level();
+            assert!(matches!(
+                solve_bounded::<1>(&level, &level.starting_positions.into(), u32::MAX),
+                BoundedSolution::Solved(moves) if moves.len() == 1
+            ));
+
+            // The piece is walled in away from its goal
+            let level: crate::owned_level::OwnedLevel = "
+#####
+#G#g#
+#####
+
+optimal: 1
+"
+            .parse()
+            .unwrap();
+            let level = level.level();
+            assert_eq!(
+                solve_bounded::<20>(&level, &level.starting_positions.into(), u32::MAX),
+                BoundedSolution::Unsolvable
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "std")]
+use crate::level_run::LevelRunState;
+use crate::{
+    Level, PieceMap, Vector,
+    level_run::{Direction, Move},
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, VecDeque, hash_map::Entry};
+use strum::IntoEnumIterator;
+
+#[cfg(feature = "std")]
+pub mod analysis;
+pub mod bounded;
+
+type Positions = PieceMap<Vector<u8>>;
+
+// Finds a shortest sequence of moves that wins the level from its starting positions
+#[cfg(feature = "std")]
+pub fn solve(level: &Level) -> Option<Vec<Move>> {
+    solve_from(level, &level.starting_positions.into())
+}
+
+// Finds a shortest sequence of moves that wins the level from arbitrary piece positions
+#[cfg(feature = "std")]
+pub fn solve_from(level: &Level, positions: &Positions) -> Option<Vec<Move>> {
+    let start = LevelRunState {
+        level,
+        positions: positions.clone(),
+    };
+
+    // Breadth-first search, where each reached position records the position and move it was reached by
+    let mut reached: HashMap<Positions, Option<(Positions, Move)>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    reached.insert(start.positions.clone(), None);
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        if state.is_winning() {
+            return Some(unwind_moves(&reached, &state.positions));
+        }
+
+        for muv in all_moves(level) {
+            let mut new_state = state.clone();
+
+            if new_state.attempt_move(muv).is_some()
+                && let Entry::Vacant(entry) = reached.entry(new_state.positions.clone())
+            {
+                entry.insert(Some((state.positions.clone(), muv)));
+                queue.push_back(new_state);
+            }
+        }
+    }
+
+    None
+}
+
+fn all_moves(level: &Level) -> impl Iterator<Item = Move> {
+    level
+        .all_pieces()
+        .flat_map(|piece| Direction::iter().map(move |d| Move::new(piece, d)))
+}
+
+// Follows the recorded moves back to the starting position
+#[cfg(feature = "std")]
+fn unwind_moves(
+    reached: &HashMap<Positions, Option<(Positions, Move)>>,
+    positions: &Positions,
+) -> Vec<Move> {
+    let mut moves = Vec::new();
+    let mut positions = positions;
+
+    while let Some((previous, muv)) = &reached[positions] {
+        moves.push(*muv);
+        positions = previous;
+    }
+    moves.reverse();
+
+    moves
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Piece, levels::LEVELS, owned_level::OwnedLevel};
+
+    #[test]
+    fn solve_levels() {
+        for level in LEVELS.iter().take(20) {
+            let moves = solve(level).unwrap();
+            assert_eq!(moves.len(), level.optimal_moves as usize);
+
+            // The moves should actually win the level
+            assert_eq!(level.verify_solution(&moves), Ok(level.optimal_moves));
+        }
+    }
+
+    #[test]
+    fn four_pieces() {
+        let level: OwnedLevel = "
+######
+#g  P#
+#o  B#
+#b  O#
+#p  G#
+######
+"
+        .parse()
+        .unwrap();
+        let level = level.level();
+        let moves = solve(&level).unwrap();
+
+        assert_eq!(level.num_pieces(), 4);
+        assert_eq!(moves.len(), level.optimal_moves as usize);
+        assert!(moves.iter().any(|m| m.piece == Piece::Purple));
+        assert_eq!(level.verify_solution(&moves), Ok(level.optimal_moves));
+    }
+}
@@ -38,6 +38,9 @@ pub struct PyGamerController<'a> {
     joystick_reader: JoystickReader,
     button_reader: ButtonReader,
     last_direction: Option<Direction>,
+    // Select modifies the other buttons while held, so it only acts by itself once released
+    select_held: bool,
+    select_combined: bool,
 }
 impl<'a> PyGamerController<'a> {
     pub fn new(
@@ -52,33 +55,76 @@ impl<'a> PyGamerController<'a> {
             joystick_reader,
             button_reader,
             last_direction: None,
+            select_held: false,
+            select_combined: false,
         }
     }
 }
+impl PyGamerController<'_> {
+    const POLL_PERIOD_MS: u8 = 5;
+
+    // Waits for one polling period and returns an action if one occurred
+    fn check_action(&mut self) -> Option<ControlAction> {
+        self.delay.borrow_mut().delay_ms(Self::POLL_PERIOD_MS);
+
+        // Need to debounce the joystick
+        let old_direction = self.last_direction;
+        let new_direction = self.joystick_reader.direction(&mut self.joystick_adc);
+        self.last_direction = new_direction;
+
+        if new_direction != old_direction
+            && let Some(dir) = new_direction
+        {
+            return Some(ControlAction::Move(dir));
+        }
+        for key in self.button_reader.events() {
+            return Some(match key {
+                Keys::SelectDown => {
+                    self.select_held = true;
+                    self.select_combined = false;
+                    continue;
+                }
+                Keys::SelectUp => {
+                    self.select_held = false;
+                    if self.select_combined {
+                        continue;
+                    }
+                    ControlAction::Select
+                }
+                Keys::StartDown if self.select_held => {
+                    self.select_combined = true;
+                    ControlAction::SelectStart
+                }
+                Keys::StartDown => ControlAction::Start,
+                Keys::BDown if self.select_held => {
+                    self.select_combined = true;
+                    ControlAction::SelectB
+                }
+                Keys::BDown => ControlAction::B,
+                Keys::ADown if self.select_held => {
+                    self.select_combined = true;
+                    ControlAction::SelectA
+                }
+                Keys::ADown => ControlAction::A,
+                _ => continue,
+            });
+        }
+
+        None
+    }
+}
 impl Controller for PyGamerController<'_> {
     fn wait_for_action(&mut self) -> GameResult<ControlAction> {
         loop {
-            self.delay.borrow_mut().delay_ms(5u8);
-
-            // Need to debounce the joystick
-            let old_direction = self.last_direction;
-            let new_direction = self.joystick_reader.direction(&mut self.joystick_adc);
-            self.last_direction = new_direction;
-
-            if new_direction != old_direction
-                && let Some(dir) = new_direction
-            {
-                break GameResult::Continue(ControlAction::Move(dir));
-            }
-            for key in self.button_reader.events() {
-                return GameResult::Continue(match key {
-                    Keys::SelectDown => ControlAction::Select,
-                    Keys::StartDown => ControlAction::Start,
-                    Keys::BDown => ControlAction::B,
-                    Keys::ADown => ControlAction::A,
-                    _ => continue,
-                });
+            if let Some(action) = self.check_action() {
+                break GameResult::Continue(action);
             }
         }
     }
+
+    fn poll_action(&mut self, timeout_ms: u32) -> GameResult<Option<ControlAction>> {
+        GameResult::Continue(
+            (0..timeout_ms / Self::POLL_PERIOD_MS as u32).find_map(|_| self.check_action()),
+        )
+    }
 }
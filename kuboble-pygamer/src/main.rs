@@ -11,7 +11,7 @@ use pygamer::hal::adc::Adc;
 use pygamer::hal::clock::GenericClockController;
 use pygamer::hal::delay::Delay;
 use pygamer::pac::gclk::pchctrl::Genselect;
-use pygamer::{entry, pac, Pins};
+use pygamer::{Pins, entry, pac};
 use pygamer_engine::run_game;
 
 mod controls;
@@ -3,9 +3,9 @@ use core::iter::{repeat, repeat_n};
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use kuboble_core::{LevelRating, Piece};
-use pygamer::{pins::DisplayDriver, NeopixelSpi};
+use pygamer::{NeopixelSpi, pins::DisplayDriver};
 use pygamer_engine::prelude::*;
-use smart_leds::{SmartLedsWrite, RGB};
+use smart_leds::{RGB, SmartLedsWrite};
 use ws2812_spi::Ws2812;
 
 pub type NeoPixels = Ws2812<NeopixelSpi>;
@@ -19,6 +19,8 @@ impl PieceExt for Piece {
             Piece::Green => RGB::new(0, 5, 0),
             Piece::Orange => RGB::new(5, 3, 0),
             Piece::Blue => RGB::new(0, 0, 10),
+            Piece::Purple => RGB::new(5, 0, 7),
+            Piece::Grey1 | Piece::Grey2 | Piece::Grey3 => RGB::new(2, 2, 2),
         }
     }
 }
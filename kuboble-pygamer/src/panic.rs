@@ -68,8 +68,8 @@ fn panic(_info: &PanicInfo) -> ! {
     // In release, just light the LED
     #[cfg(not(debug_assertions))]
     {
-        use pygamer::hal::prelude::*;
         use pygamer::RedLed;
+        use pygamer::hal::prelude::*;
 
         let mut red_led: RedLed = pins.led_pin.into();
         red_led.set_high().unwrap();
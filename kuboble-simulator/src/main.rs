@@ -1,44 +1,88 @@
+use anyhow::Context;
 use derive_new::new;
 use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
 use embedded_graphics_simulator::{
-    sdl2::Keycode, BinaryColorTheme, OutputSettings, SimulatorDisplay, SimulatorEvent, Window,
+    BinaryColorTheme, OutputSettings, SimulatorDisplay, SimulatorEvent, Window, sdl2::Keycode,
+};
+use kuboble_core::{
+    LevelRating, Piece, Rules, level_run::Direction, level_select::LevelProgress,
+    owned_level::OwnedLevel, solver::solve,
 };
-use kuboble_core::{level_run::Direction, level_select::LevelProgress, LevelRating, Piece};
 use pygamer_engine::prelude::*;
-use std::{cell::RefCell, fs::File, u32};
+use std::{
+    cell::RefCell,
+    fs::File,
+    path::Path,
+    time::{Duration, Instant},
+    u32,
+};
 
 #[derive(new)]
 struct SimulatorController<'a> {
     window: &'a RefCell<Window>,
 }
-impl Controller for SimulatorController<'_> {
-    fn wait_for_action(&mut self) -> GameResult<ControlAction> {
+impl SimulatorController<'_> {
+    // Returns the next action if one is pending, without waiting
+    fn check_action(&mut self) -> Option<GameResult<ControlAction>> {
         let mut window = self.window.borrow_mut();
 
-        loop {
-            for event in window.events() {
-                return GameResult::Continue(match event {
-                    SimulatorEvent::KeyDown {
-                        keycode,
-                        keymod: _,
-                        repeat: _,
-                    } => match keycode {
-                        Keycode::Up => ControlAction::Move(Direction::Up),
-                        Keycode::Down => ControlAction::Move(Direction::Down),
-                        Keycode::Left => ControlAction::Move(Direction::Left),
-                        Keycode::Right => ControlAction::Move(Direction::Right),
-                        Keycode::A => ControlAction::A,
-                        Keycode::S => ControlAction::B,
-                        Keycode::Z => ControlAction::Start,
-                        Keycode::X => ControlAction::Select,
-                        _ => continue,
-                    },
-                    SimulatorEvent::Quit => return GameResult::Exit,
+        for event in window.events() {
+            return Some(GameResult::Continue(match event {
+                SimulatorEvent::KeyDown {
+                    keycode,
+                    keymod: _,
+                    repeat: _,
+                } => match keycode {
+                    Keycode::Up => ControlAction::Move(Direction::Up),
+                    Keycode::Down => ControlAction::Move(Direction::Down),
+                    Keycode::Left => ControlAction::Move(Direction::Left),
+                    Keycode::Right => ControlAction::Move(Direction::Right),
+                    Keycode::A => ControlAction::A,
+                    Keycode::S => ControlAction::B,
+                    Keycode::Z => ControlAction::Start,
+                    Keycode::X => ControlAction::Select,
+                    // Stand in for the button combinations
+                    Keycode::D => ControlAction::SelectB,
+                    Keycode::Q => ControlAction::SelectA,
+                    Keycode::H => ControlAction::SelectStart,
+                    // Number keys pick pieces directly
+                    Keycode::Num1 => ControlAction::Piece(Piece::Green),
+                    Keycode::Num2 => ControlAction::Piece(Piece::Orange),
+                    Keycode::Num3 => ControlAction::Piece(Piece::Blue),
+                    Keycode::Num4 => ControlAction::Piece(Piece::Purple),
+                    Keycode::Num5 => ControlAction::Piece(Piece::Grey1),
+                    Keycode::Num6 => ControlAction::Piece(Piece::Grey2),
+                    Keycode::Num7 => ControlAction::Piece(Piece::Grey3),
                     _ => continue,
-                });
+                },
+                SimulatorEvent::Quit => return Some(GameResult::Exit),
+                _ => continue,
+            }));
+        }
+
+        None
+    }
+}
+impl Controller for SimulatorController<'_> {
+    fn wait_for_action(&mut self) -> GameResult<ControlAction> {
+        loop {
+            if let Some(result) = self.check_action() {
+                break result;
             }
         }
     }
+
+    fn poll_action(&mut self, timeout_ms: u32) -> GameResult<Option<ControlAction>> {
+        let start = Instant::now();
+
+        while start.elapsed() < Duration::from_millis(timeout_ms.into()) {
+            if let Some(result) = self.check_action() {
+                return GameResult::Continue(Some(result?));
+            }
+        }
+
+        GameResult::Continue(None)
+    }
 }
 
 struct SimulatorOutput<'a> {
@@ -100,11 +144,39 @@ impl GameOutput for SimulatorOutput<'_> {
 const PROGRESS_FILE_NAME: &str = "level-progress.json";
 
 fn load_progress() -> Result<LevelProgress, anyhow::Error> {
-    Ok(serde_json::from_reader(File::open(PROGRESS_FILE_NAME)?)?)
+    let mut level_progress: LevelProgress =
+        serde_json::from_reader(File::open(PROGRESS_FILE_NAME)?)?;
+
+    let num_invalid = level_progress.verify_solutions();
+    if num_invalid > 0 {
+        println!("Downgraded {num_invalid} levels with invalid optimal solutions");
+    }
+
+    Ok(level_progress)
 }
 
 fn main() -> anyhow::Result<()> {
-    let mut level_progress = load_progress().unwrap_or_else(|_| LevelProgress::default());
+    // Custom levels can be played without rebuilding by passing their file
+    let custom_levels = std::env::args()
+        .nth(1)
+        .map(|path| {
+            OwnedLevel::load_file(Path::new(&path))
+                .with_context(|| format!("could not load '{path}'"))
+        })
+        .transpose()?;
+
+    // Ratings are based on the declared optimal number of moves, so it has to be right
+    for (idx, level) in custom_levels.iter().flatten().enumerate() {
+        let moves =
+            solve(&level.level()).with_context(|| format!("level {} cannot be solved", idx + 1))?;
+        anyhow::ensure!(
+            moves.len() == level.level().optimal_moves as usize,
+            "level {} has an optimal of {} moves but can be solved in {}",
+            idx + 1,
+            level.level().optimal_moves,
+            moves.len()
+        );
+    }
 
     let window = RefCell::new(Window::new(
         "Kuboble",
@@ -116,6 +188,20 @@ fn main() -> anyhow::Result<()> {
         },
     ));
 
+    if let Some(custom_levels) = custom_levels {
+        let levels: Vec<_> = custom_levels.iter().map(|l| l.level()).collect();
+
+        run_levels(
+            SimulatorController::new(&window),
+            SimulatorOutput::new(&window),
+            &levels,
+            &Rules::default(),
+        );
+
+        return Ok(());
+    }
+
+    let mut level_progress = load_progress().unwrap_or_else(|_| LevelProgress::default());
     run_game(
         SimulatorController::new(&window),
         SimulatorOutput::new(&window),
@@ -1,12 +1,19 @@
 #![feature(let_chains)]
 
-use clap::Parser;
-use euclid::{Point2D, Size2D, Transform2D, Vector2D};
+use clap::{Parser, Subcommand};
+use euclid::{Point2D, Size2D, Transform2D};
 use grid::Grid;
-use itertools::{iproduct, Itertools};
-use kuboble_core::{Piece, Space};
-use serde::Deserialize;
-use std::{fs::File, io::BufReader, io::Write, path::PathBuf};
+use itertools::{Itertools, iproduct};
+use kuboble_core::{
+    Level, Piece, Space, Vector,
+    generator::{Generator, GeneratorSettings},
+    levels::{LEVELS, MAX_OPTIMAL_MOVES},
+    owned_level::{OwnedLevel, void_extraneous_walls},
+    solver::analysis::analyze,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::ser::PrettyFormatter;
+use std::{fs::File, io::BufReader, path::PathBuf};
 use strum::IntoEnumIterator;
 
 trait SizeExt {
@@ -19,6 +26,16 @@ impl SizeExt for Size<JsonLevel> {
     }
 }
 
+trait RustSizeExt {
+    fn into_json(self) -> Size<JsonLevel>;
+}
+impl RustSizeExt for Size<RustLevel> {
+    fn into_json(self) -> Size<JsonLevel> {
+        // Undoes `SizeExt::into_rust`
+        Size::new(self.height - 2, self.width - 2)
+    }
+}
+
 trait PointExt {
     fn into_rust(self, size: Size<JsonLevel>) -> Point<RustLevel>;
 }
@@ -35,7 +52,23 @@ impl PointExt for Point<JsonLevel> {
     }
 }
 
-#[derive(Debug, Deserialize)]
+trait RustPointExt {
+    fn into_json(self, size: Size<JsonLevel>) -> Point<JsonLevel>;
+}
+impl RustPointExt for Point<RustLevel> {
+    fn into_json(self, size: Size<JsonLevel>) -> Point<JsonLevel> {
+        // Precalculated inverse of the matrix in `PointExt::into_rust`
+        let transform: Transform2D<i8, RustLevel, JsonLevel> =
+            Transform2D::new(0, 1, -1, 0, size.width as i8, -1);
+
+        transform
+            .transform_point(self.try_cast().unwrap())
+            .try_cast()
+            .unwrap()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 struct JsonLevel {
     x: u8,
     y: u8,
@@ -55,36 +88,64 @@ impl JsonLevel {
 
         Point::new(n % width, n / width)
     }
+
+    fn from_level(level: &Level) -> anyhow::Result<Self> {
+        let size = Size::<RustLevel>::new(level.size.x, level.size.y).into_json();
+        let space_num = |p: Vector<u8>| {
+            let point = Point::new(p.x, p.y).into_json(size);
+
+            point.y * size.width + point.x
+        };
+
+        if !level.starting_positions.blockers.is_empty() {
+            anyhow::bail!("blockers cannot be exported");
+        }
+
+        // Everything within the outer walls that is not free is an additional wall
+        let mut goals = Vec::new();
+        let mut walls = Vec::new();
+        for position in level
+            .all_positions()
+            .filter(|p| p.x > 0 && p.y > 0 && p.x < level.size.x - 1 && p.y < level.size.y - 1)
+        {
+            match level.get_space(position) {
+                Space::Free => {}
+                Space::Goal(piece) => goals.push((piece as u8, space_num(position))),
+                Space::Wall | Space::Void => walls.push(space_num(position)),
+                space => anyhow::bail!("'{space}' spaces cannot be exported"),
+            }
+        }
+        goals.sort();
+        walls.sort();
+
+        // The JSON format has exactly one goal for each piece
+        if !goals.iter().map(|(p, _)| *p).eq(0..level.num_pieces()) {
+            anyhow::bail!("only levels with exactly one goal for each piece can be exported");
+        }
+
+        Ok(Self {
+            x: size.width,
+            y: size.height,
+            _n: level.starting_positions.pieces.len(),
+            s: level
+                .starting_positions
+                .pieces
+                .iter()
+                .map(|p| space_num(*p))
+                .collect(),
+            t: goals.into_iter().map(|(_, n)| n).collect(),
+            o: walls,
+        })
+    }
 }
 
 trait GridExt {
-    fn get_space(&self, point: Point<RustLevel>) -> Option<Space>;
     fn set_space(&mut self, point: Point<RustLevel>, space: Space);
-    fn is_surrounded_by(&self, point: Point<RustLevel>, space: Space) -> bool;
 }
 impl GridExt for Grid<Space> {
-    fn get_space(&self, point: Point<RustLevel>) -> Option<Space> {
-        self.get(point.y as usize, point.x as usize).copied()
-    }
-
     fn set_space(&mut self, point: Point<RustLevel>, space: Space) {
         *self.get_mut(point.y as usize, point.x as usize).unwrap() = space;
     }
-
-    fn is_surrounded_by(&self, point: Point<RustLevel>, space: Space) -> bool {
-        iproduct!(-1i8..=1, -1i8..=1)
-            .map(|(dy, dx)| Vector2D::new(dx, dy))
-            .filter(|dv| *dv != Vector2D::zero())
-            .all(|dv| {
-                if let Some(p) = (point.try_cast().unwrap() + dv).try_cast()
-                    && let Some(s) = self.get_space(p)
-                {
-                    s == space
-                } else {
-                    true
-                }
-            })
-    }
 }
 
 type Size<U> = Size2D<u8, U>;
@@ -121,17 +182,12 @@ impl RustLevel {
             );
         }
 
-        // Remove extraneous walls by voiding wall spaces surrounded by other walls (or the edge of the level)
-        let void_points = iproduct!(0..spaces.size().0, 0..spaces.size().1)
-            .map(|(y, x)| Point::new(x as u8, y as u8))
-            .filter(|p| {
-                spaces.get_space(*p).unwrap() == Space::Wall
-                    && spaces.is_surrounded_by(*p, Space::Wall)
-            })
-            .collect_vec();
-        for point in void_points {
-            spaces.set_space(point, Space::Void)
-        }
+        let mut flat_spaces = spaces.into_vec();
+        void_extraneous_walls(
+            Vector::new(rust_size.width, rust_size.height),
+            &mut flat_spaces,
+        );
+        let mut spaces = Grid::from_vec(flat_spaces, rust_size.width as usize);
 
         // Fill in goal spaces
         for (n, piece) in value.t.iter().zip(Piece::iter()) {
@@ -157,60 +213,125 @@ impl RustLevel {
         }
     }
 }
-impl std::fmt::Display for RustLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "// Level {}", self.level_num)?;
-        writeln!(f, "level! {{")?;
-        writeln!(f, "\tspaces: &[")?;
-        for row in self.spaces.iter_rows() {
-            writeln!(f, "\t\t\"{}\",", row.map(|s| char::from(*s)).join(""))?;
-        }
-        writeln!(f, "\t],")?;
-        writeln!(
-            f,
-            "\tpositions: &[{}],",
+impl RustLevel {
+    fn to_owned_level(&self) -> anyhow::Result<OwnedLevel> {
+        Ok(OwnedLevel::new(
+            &self
+                .spaces
+                .iter_rows()
+                .map(|row| row.map(|s| char::from(*s)).collect::<String>())
+                .collect_vec(),
             self.positions
                 .iter()
-                .map(|p| format!("Vector::new{p:?}"))
-                .join(", ")
-        )?;
-        writeln!(f, "\toptimal: {},", self.optimal)?;
-        write!(f, "}},")?;
-
-        Ok(())
+                .map(|p| Vector::new(p.x, p.y))
+                .collect(),
+            Vec::new(),
+            self.optimal,
+        )?)
     }
 }
 
+// Generates the level data file of `kuboble-core`, which has one level per line
+fn levels_data(rust_levels: &[RustLevel]) -> anyhow::Result<String> {
+    rust_levels
+        .iter()
+        .map(|rl| Ok(serde_json::to_string(&rl.to_owned_level()?)? + "\n"))
+        .collect()
+}
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Tools for working with Kuboble level definitions.")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
 // It would be nice to be able to use a doc string for the about but evidently there is no way
 // to do this and have multiple lines:
 // https://github.com/clap-rs/clap/issues/5003
-#[derive(Parser, Debug)]
-#[command(
-    version,
-    about = r#"Converts Kuboble level definitions from JSON to Rust code and prints some metrics.
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(
+        about = "Converts Kuboble level definitions from JSON to the level data file and prints some metrics.",
+        long_about = r#"Converts Kuboble level definitions from JSON to the level data file and prints some metrics.
     
 The JSON level definition file must be in the format defined here: https://github.com/redking00/kubobleSolverJS
-Unfortunately, this does not include the the optimal number of moves for each level, so these must be specified in an additional file"#
-)]
-struct Args {
-    /// JSON level definition file to convert.
-    json_level_path: PathBuf,
+Unfortunately, this does not include the the optimal number of moves for each level, so these must be specified in an additional file.
+The output is the `levels.jsonl` data file of `kuboble-core`, from which its levels are generated when it is built."#
+    )]
+    Convert {
+        /// JSON level definition file to convert.
+        json_level_path: PathBuf,
 
-    /// JSON optimal number of moves file.
-    json_optimal_moves: PathBuf,
-}
+        /// JSON optimal number of moves file.
+        json_optimal_moves: PathBuf,
 
-fn main() -> anyhow::Result<()> {
-    // Parse command line arguments
-    let args = Args::parse();
+        /// Level data file to write, which defaults to the name of the level definition file.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Fail if the existing output file differs instead of writing it.
+        #[arg(long)]
+        check: bool,
+    },
+    /// Generates random levels with an optimal number of moves in a given range.
+    Generate {
+        /// Number of levels to generate.
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+
+        /// Number of pieces in each level.
+        #[arg(short, long, default_value_t = 2)]
+        pieces: u8,
+
+        /// Number of goals in addition to one for each piece, each for a random piece.
+        #[arg(long, default_value_t = 0)]
+        extra_goals: u8,
+
+        /// Number of goals that any piece can finish on.
+        #[arg(long, default_value_t = 0)]
+        any_goals: u8,
+
+        /// Minimum optimal number of moves.
+        #[arg(long, default_value_t = 8)]
+        min_moves: u8,
 
+        /// Maximum optimal number of moves.
+        #[arg(long, default_value_t = 20)]
+        max_moves: u8,
+
+        /// Output the levels as a JSON array instead of lines for the level data file.
+        #[arg(short, long)]
+        json: bool,
+    },
+    #[command(
+        about = "Converts levels back to the JSON level definition format and prints them.",
+        long_about = r#"Converts levels back to the JSON level definition format and prints them.
+
+This is the reverse of the convert command, for sending levels back to https://github.com/redking00/kubobleSolverJS
+The optimal number of moves is not part of this format and so is not included."#
+    )]
+    Export {
+        /// Level file to export, either in the text format (.txt) or JSON, instead of the built-in levels.
+        level_path: Option<PathBuf>,
+    },
+    /// Explores the state space of every built-in level and prints some metrics.
+    Analyze,
+}
+
+fn convert(
+    json_level_path: PathBuf,
+    json_optimal_moves: PathBuf,
+    output: Option<PathBuf>,
+    check: bool,
+) -> anyhow::Result<()> {
     // Parse the level definition file
     let json_levels: Vec<JsonLevel> =
-        serde_json::from_reader(BufReader::new(File::open(&args.json_level_path)?))?;
+        serde_json::from_reader(BufReader::new(File::open(&json_level_path)?))?;
 
     // Parse the optimal moves file
     let optimal_moves: Vec<u8> =
-        serde_json::from_reader(BufReader::new(File::open(&args.json_optimal_moves)?))?;
+        serde_json::from_reader(BufReader::new(File::open(&json_optimal_moves)?))?;
 
     // Determine max number of pieces
     let max_pieces = json_levels.iter().map(|jl| jl.s.len()).max().unwrap();
@@ -241,14 +362,171 @@ fn main() -> anyhow::Result<()> {
         level.level_num, level.size
     );
 
-    let mut rust_file = File::create(
-        PathBuf::from(args.json_level_path.file_stem().unwrap()).with_extension("rs"),
-    )?;
+    let output = output.unwrap_or_else(|| {
+        PathBuf::from(json_level_path.file_stem().unwrap()).with_extension("jsonl")
+    });
+    let data = levels_data(&rust_levels)?;
+
+    if check {
+        if std::fs::read_to_string(&output)? != data {
+            anyhow::bail!(
+                "{} is out of date with the level definitions",
+                output.display()
+            );
+        }
+        println!("{} is up to date", output.display());
+    } else {
+        std::fs::write(&output, data)?;
+    }
+
+    Ok(())
+}
+
+fn generate(settings: GeneratorSettings, count: usize, json: bool) -> anyhow::Result<()> {
+    // The generator would otherwise never find a level
+    let max_pieces = Piece::colored().count();
+    anyhow::ensure!(
+        (1..=max_pieces).contains(&(settings.num_pieces as usize)),
+        "there must be between 1 and {max_pieces} pieces"
+    );
+    let (min_moves, max_moves) = (
+        *settings.optimal_moves.start(),
+        *settings.optimal_moves.end(),
+    );
+    anyhow::ensure!(
+        min_moves <= max_moves,
+        "the minimum number of moves is more than the maximum"
+    );
+    anyhow::ensure!(
+        min_moves as usize <= MAX_OPTIMAL_MOVES,
+        "levels can have at most {MAX_OPTIMAL_MOVES} moves"
+    );
+
+    let levels = Generator::new(settings, rand::rng()).take(count);
 
-    // Now convert the levels as save as Rust code
-    for rust_level in rust_levels {
-        writeln!(rust_file, "{rust_level}")?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&levels.collect_vec())?);
+    } else {
+        for level in levels {
+            println!("{}", serde_json::to_string(&level)?);
+        }
     }
 
     Ok(())
 }
+
+fn export(level_path: Option<PathBuf>) -> anyhow::Result<()> {
+    let json_levels: Vec<JsonLevel> = match level_path {
+        Some(path) => OwnedLevel::load_file(&path)?
+            .iter()
+            .map(|l| JsonLevel::from_level(&l.level()))
+            .try_collect()?,
+        None => LEVELS.iter().map(JsonLevel::from_level).try_collect()?,
+    };
+
+    // Match the indentation of the upstream file
+    let mut serializer = serde_json::Serializer::with_formatter(
+        std::io::stdout().lock(),
+        PrettyFormatter::with_indent(b"    "),
+    );
+    json_levels.serialize(&mut serializer)?;
+    println!();
+
+    Ok(())
+}
+
+fn analyze_levels() {
+    for (idx, level) in LEVELS.iter().enumerate() {
+        let analysis = analyze(level);
+
+        println!(
+            "Level {}: {} states, {:.2} average branching, {} dead ends, {} optimal solutions",
+            idx + 1,
+            analysis.num_states,
+            analysis.average_branching,
+            analysis.num_dead_ends,
+            analysis.num_optimal_solutions
+        );
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    // Parse command line arguments
+    let args = Args::parse();
+
+    match args.command {
+        Command::Convert {
+            json_level_path,
+            json_optimal_moves,
+            output,
+            check,
+        } => convert(json_level_path, json_optimal_moves, output, check),
+        Command::Generate {
+            count,
+            pieces,
+            extra_goals,
+            any_goals,
+            min_moves,
+            max_moves,
+            json,
+        } => generate(
+            GeneratorSettings {
+                num_pieces: pieces,
+                num_extra_goals: extra_goals,
+                num_any_goals: any_goals,
+                optimal_moves: min_moves..=max_moves,
+                ..Default::default()
+            },
+            count,
+            json,
+        ),
+        Command::Export { level_path } => export(level_path),
+        Command::Analyze => {
+            analyze_levels();
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_round_trip() {
+        let mut json_levels: Vec<JsonLevel> =
+            serde_json::from_str(include_str!("../levels.json")).unwrap();
+        assert_eq!(json_levels.len(), LEVELS.len());
+
+        for (idx, (level, json_level)) in LEVELS.iter().zip(json_levels.iter_mut()).enumerate() {
+            // The additional walls are not in any particular order upstream
+            json_level.o.sort();
+
+            assert_eq!(
+                JsonLevel::from_level(level).unwrap(),
+                *json_level,
+                "level {} did not round trip",
+                idx + 1
+            );
+        }
+    }
+
+    #[test]
+    fn levels_data_up_to_date() {
+        let json_levels: Vec<JsonLevel> =
+            serde_json::from_str(include_str!("../levels.json")).unwrap();
+        let optimal_moves: Vec<u8> =
+            serde_json::from_str(include_str!("../optimal_moves.json")).unwrap();
+
+        let rust_levels = json_levels
+            .into_iter()
+            .zip(optimal_moves)
+            .enumerate()
+            .map(|(idx, (jl, optimal))| RustLevel::from_json_level(jl, idx as u16 + 1, optimal))
+            .collect_vec();
+
+        assert!(
+            levels_data(&rust_levels).unwrap() == include_str!("../../kuboble-core/levels.jsonl")
+        );
+    }
+}
@@ -1,8 +1,8 @@
-use crate::{assets, TryIntoSize, SPACE_RECT, SPACE_SIZE};
 use crate::{
-    display::FONT, ControlAction, Controller, GameOutput, GameResult, IntoPoint, PieceExt,
+    ControlAction, Controller, GameOutput, GameResult, IntoPoint, PieceExt, display::FONT,
 };
-use arrayvec::ArrayString;
+use crate::{SPACE_RECT, SPACE_SIZE, TryIntoSize, assets};
+use arrayvec::{ArrayString, ArrayVec};
 use core::fmt::Write;
 use embedded_graphics::{
     mono_font::{MonoTextStyle, MonoTextStyleBuilder},
@@ -15,10 +15,14 @@ use embedded_graphics_framebuf::FrameBuf;
 use embedded_sprites::sprite::Sprite;
 use kuboble_core::BufferedRenderer;
 use kuboble_core::{
-    level_run::{render::LevelRunRenderer, Action, LevelRun, PieceSlid},
+    Level, Piece, Rules, Space, Vector,
+    level_run::{
+        Action, Direction, Hint, LevelRun, Move, Outlook, PieceSlid, SlideStrip, SuspendedRun,
+        render::LevelRunRenderer,
+        replay::{LevelReplay, ReplayAction},
+    },
     level_select::{LevelInfo, LevelStatus},
-    levels::MAX_STRIP_SIZE,
-    Level, Piece, Space, Vector,
+    levels::{MAX_OPTIMAL_MOVES, MAX_STRIP_SIZE},
 };
 
 pub struct LevelRenderer<'a, G> {
@@ -26,6 +30,7 @@ pub struct LevelRenderer<'a, G> {
     level_rect: Rectangle,
     display_center: Point,
     at_max_moves: bool,
+    hint_shown: bool,
 }
 impl<'a, G: GameOutput> LevelRenderer<'a, G>
 where
@@ -42,6 +47,45 @@ where
             level_rect: Rectangle::new(display_center - level_size / 2, level_size),
             display_center,
             at_max_moves: true,
+            hint_shown: false,
+        }
+    }
+
+    // Right-aligned on the bottom line so that it never runs into the move counter
+    fn draw_hint_text(&mut self, text: &str, color: Rgb565) {
+        let size = self.output.size();
+
+        Text::with_text_style(
+            text,
+            Point::new(size.width as i32, size.height as i32) - Point::new(1, 1),
+            MonoTextStyleBuilder::new()
+                .font(&FONT)
+                .text_color(color)
+                .background_color(Rgb565::BLACK)
+                .build(),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Bottom)
+                .build(),
+        )
+        .draw(self.output)
+        .unwrap();
+
+        self.hint_shown = true;
+    }
+
+    // Shown while searching for a hint, which can take a moment
+    fn show_thinking(&mut self) {
+        self.clear_hint();
+        self.draw_hint_text("Thinking...", Rgb565::WHITE);
+        self.flush();
+    }
+
+    fn clear_hint(&mut self) {
+        if self.hint_shown {
+            // Overwrite the longest possible hint with blanks
+            self.draw_hint_text("           ", Rgb565::BLACK);
+            self.hint_shown = false;
         }
     }
 
@@ -68,55 +112,80 @@ where
                     .draw(target)
                     .unwrap();
             }
-            Space::Goal(piece) => {
-                Sprite::new(point, &assets::spaces::FREE)
+            Space::Stop => {
+                Sprite::new(point, &assets::spaces::STOP)
                     .draw(target)
                     .unwrap();
-
-                Rectangle::new(point + Point::new(2, 2), SPACE_RECT.size - Size::new(3, 3))
-                    .into_styled(
-                        PrimitiveStyleBuilder::new()
-                            .stroke_color(piece.display_color())
-                            .stroke_width(1)
-                            .stroke_alignment(StrokeAlignment::Inside)
-                            .build(),
-                    )
+            }
+            Space::OneWay(direction) => {
+                Sprite::new(
+                    point,
+                    match direction {
+                        Direction::Up => &assets::spaces::ONE_WAY_UP,
+                        Direction::Down => &assets::spaces::ONE_WAY_DOWN,
+                        Direction::Left => &assets::spaces::ONE_WAY_LEFT,
+                        Direction::Right => &assets::spaces::ONE_WAY_RIGHT,
+                    },
+                )
+                .draw(target)
+                .unwrap();
+            }
+            Space::Teleport(num) => {
+                Sprite::new(point, &assets::spaces::TELEPORT)
                     .draw(target)
                     .unwrap();
+
+                // Label the teleporter so that its partner can be found
+                let mut label: ArrayString<1> = ArrayString::new();
+                write!(label, "{num}").unwrap();
+                Text::with_text_style(
+                    &label,
+                    point + SPACE_RECT.center(),
+                    MonoTextStyle::new(&FONT, Rgb565::new(0, 37, 21)),
+                    TextStyleBuilder::new()
+                        .alignment(Alignment::Center)
+                        .baseline(Baseline::Middle)
+                        .build(),
+                )
+                .draw(target)
+                .unwrap();
             }
+            Space::Goal(piece) => Self::draw_goal_absolute(target, point, piece.display_color()),
+            // Any piece can finish here, so no particular color is used
+            Space::AnyGoal => Self::draw_goal_absolute(target, point, Rgb565::WHITE),
         }
     }
 
-    fn draw_piece_absolute(&mut self, point: Point, piece: Piece, is_active: bool) {
-        Sprite::new(point, &piece.image(is_active))
-            .draw(self.output)
+    fn draw_goal_absolute<D: DrawTarget<Color = Rgb565>>(
+        target: &mut D,
+        point: Point,
+        color: Rgb565,
+    ) where
+        D::Error: core::fmt::Debug,
+    {
+        Sprite::new(point, &assets::spaces::FREE)
+            .draw(target)
             .unwrap();
-    }
-}
-impl<G: GameOutput> BufferedRenderer for LevelRenderer<'_, G> {
-    fn flush(&mut self) {
-        self.output.render();
-    }
-}
-impl<G: GameOutput> LevelRunRenderer for LevelRenderer<'_, G>
-where
-    G::Error: core::fmt::Debug,
-{
-    fn draw_space(&mut self, position: Vector<u8>, space: Space) {
-        Self::draw_space_absolute(self.output, self.absolute_position(position), space);
-    }
 
-    fn draw_piece(&mut self, position: Vector<u8>, piece: Piece, is_active: bool) {
-        self.draw_piece_absolute(self.absolute_position(position), piece, is_active);
+        Rectangle::new(point + Point::new(2, 2), SPACE_RECT.size - Size::new(3, 3))
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(color)
+                    .stroke_width(1)
+                    .stroke_alignment(StrokeAlignment::Inside)
+                    .build(),
+            )
+            .draw(target)
+            .unwrap();
     }
 
-    fn slide_piece(&mut self, piece_slid: &PieceSlid, is_active: bool) {
+    fn slide_piece_strip(&mut self, strip: &SlideStrip, muv: Move, is_active: bool) {
         // TODO: If we ever get to upgrade `embedded-graphics`, it now has a built-in framebuffer so maybe switch to that if possible.
         // Create a frame buffer for the slide strip, though this will likely be larger than needed
         let mut framebuf_backend =
             [Rgb565::BLACK; MAX_STRIP_SIZE * SPACE_SIZE as usize * SPACE_SIZE as usize];
 
-        let (width, height) = if piece_slid.muv.direction.is_horizontal() {
+        let (width, height) = if muv.direction.is_horizontal() {
             (MAX_STRIP_SIZE * SPACE_SIZE as usize, SPACE_SIZE as usize)
         } else {
             (SPACE_SIZE as usize, MAX_STRIP_SIZE * SPACE_SIZE as usize)
@@ -124,7 +193,7 @@ where
         let mut framebuf = FrameBuf::new(&mut framebuf_backend, width, height);
 
         let variable_point = |var, con| {
-            if piece_slid.muv.direction.is_horizontal() {
+            if muv.direction.is_horizontal() {
                 Point::new(var, con)
             } else {
                 Point::new(con, var)
@@ -132,7 +201,7 @@ where
         };
 
         // Draw the background spaces into the frame buffer
-        for (i, space) in piece_slid.strip_spaces.iter().enumerate() {
+        for (i, space) in strip.spaces.iter().enumerate() {
             Self::draw_space_absolute(
                 &mut framebuf,
                 variable_point(i as i32 * SPACE_SIZE as i32, 0),
@@ -141,11 +210,11 @@ where
         }
 
         // Slide the piece
-        let dir_vector = piece_slid.muv.direction.as_vector().into_point();
-        let strip_abs_point = self.absolute_position(piece_slid.strip_top_left);
+        let dir_vector = muv.direction.as_vector().into_point();
+        let strip_abs_point = self.absolute_position(strip.top_left);
 
-        let final_pos = piece_slid.slide_distance() as i32 * SPACE_SIZE as i32;
-        let initial_buffer_point = if (-piece_slid.muv.direction).is_forward() {
+        let final_pos = strip.slide_distance() as i32 * SPACE_SIZE as i32;
+        let initial_buffer_point = if (-muv.direction).is_forward() {
             variable_point(final_pos, 0)
         } else {
             Point::new(0, 0)
@@ -169,7 +238,7 @@ where
             // Draw piece at the next position
             self.draw_piece_absolute(
                 strip_abs_point + initial_buffer_point + dir_vector * piece_pos,
-                piece_slid.muv.piece,
+                muv.piece,
                 is_active,
             );
 
@@ -184,8 +253,49 @@ where
         }
     }
 
-    fn update_num_moves(&mut self, num_moves: u8, at_maximum: bool) {
-        let mut fs: ArrayString<12> = ArrayString::new();
+    fn draw_piece_absolute(&mut self, point: Point, piece: Piece, is_active: bool) {
+        Sprite::new(point, &piece.image(is_active))
+            .draw(self.output)
+            .unwrap();
+    }
+}
+impl<G: GameOutput> BufferedRenderer for LevelRenderer<'_, G> {
+    fn flush(&mut self) {
+        self.output.render();
+    }
+}
+impl<G: GameOutput> LevelRunRenderer for LevelRenderer<'_, G>
+where
+    G::Error: core::fmt::Debug,
+{
+    fn draw_space(&mut self, position: Vector<u8>, space: Space) {
+        Self::draw_space_absolute(self.output, self.absolute_position(position), space);
+    }
+
+    fn draw_piece(&mut self, position: Vector<u8>, piece: Piece, is_active: bool) {
+        self.draw_piece_absolute(self.absolute_position(position), piece, is_active);
+    }
+
+    fn slide_piece(&mut self, piece_slid: &PieceSlid, is_active: bool) {
+        for (idx, strip) in piece_slid.strips.iter().enumerate() {
+            // Erase the piece from the teleporter it entered before it comes out of the other one
+            if idx > 0 {
+                let entered = &piece_slid.strips[idx - 1];
+                self.draw_space(
+                    entered.ending_position(piece_slid.muv.direction),
+                    entered.ending_space(piece_slid.muv.direction),
+                );
+            }
+
+            self.slide_piece_strip(strip, piece_slid.muv, is_active);
+        }
+    }
+
+    fn update_num_moves(&mut self, num_moves: u16, at_maximum: bool) {
+        // Any hint is stale once the pieces have moved
+        self.clear_hint();
+
+        let mut fs: ArrayString<14> = ArrayString::new();
 
         let num_chars = if self.at_max_moves == at_maximum {
             // Just update the number
@@ -240,18 +350,17 @@ where
         .draw(self.output)
         .unwrap();
 
-        // Draw the goal
+        // Draw the goal in the top right, leaving the bottom line to the moves and hints
         fs.clear();
         write!(fs, "Goal: {}", goal).unwrap();
-        let size = self.output.size();
 
         Text::with_text_style(
             &fs,
-            Point::new(size.width as i32, size.height as i32) - Point::new(1, 1),
+            Point::new(self.output.size().width as i32 - 1, 0),
             MonoTextStyle::new(&FONT, Rgb565::WHITE),
             TextStyleBuilder::new()
                 .alignment(Alignment::Right)
-                .baseline(Baseline::Bottom)
+                .baseline(Baseline::Top)
                 .build(),
         )
         .draw(self.output)
@@ -262,6 +371,38 @@ where
         self.output.indicate_active_piece(piece);
     }
 
+    fn show_hint(&mut self, hint: Hint) {
+        self.clear_hint();
+
+        match hint {
+            Hint::Move(muv) => {
+                let mut fs: ArrayString<11> = ArrayString::new();
+                write!(
+                    fs,
+                    "Hint: {}",
+                    match muv.direction {
+                        Direction::Up => "Up",
+                        Direction::Down => "Down",
+                        Direction::Left => "Left",
+                        Direction::Right => "Right",
+                    }
+                )
+                .unwrap();
+
+                self.draw_hint_text(&fs, muv.piece.display_color());
+            }
+            Hint::Unsolvable => self.draw_hint_text("No solution", Rgb565::RED),
+            Hint::GaveUp => self.draw_hint_text("Too hard", Rgb565::YELLOW),
+        }
+    }
+
+    fn update_outlook(&mut self, outlook: Outlook) {
+        // We do not give away the number of remaining moves, only warn when there is no way back
+        if outlook == Outlook::DeadEnd {
+            self.draw_hint_text("Dead end", Rgb565::RED);
+        }
+    }
+
     fn notify_win(&mut self, level_status: LevelStatus) {
         let mut fs: ArrayString<24> = ArrayString::new();
         write!(
@@ -288,15 +429,26 @@ where
     }
 }
 
+pub enum LevelExit {
+    Won(LevelStatus),
+    // Left part way through, with the run to resume later if any moves were made
+    Suspended(Option<SuspendedRun>),
+}
+
 pub fn play_level<C: Controller, G: GameOutput>(
     controller: &mut C,
     output: &mut G,
     level_info: &LevelInfo,
-) -> GameResult<Option<LevelStatus>>
+    rules: &Rules,
+    suspended_run: Option<&SuspendedRun>,
+) -> GameResult<LevelExit>
 where
     G::Error: core::fmt::Debug,
 {
-    let mut level_run = LevelRun::new(level_info);
+    let mut level_run = match suspended_run {
+        Some(suspended_run) => LevelRun::resume(level_info, rules, suspended_run),
+        None => LevelRun::new(level_info, rules),
+    };
     let mut renderer = LevelRenderer::new(output, level_info.level);
 
     level_run.render(&mut renderer);
@@ -305,18 +457,85 @@ where
         let action = match controller.wait_for_action()? {
             ControlAction::Move(dir) => Action::Move(dir),
             ControlAction::A => Action::ChangeActivePiece,
+            ControlAction::SelectA => Action::PreviousActivePiece,
+            ControlAction::Piece(piece) => Action::SelectPiece(piece),
             ControlAction::B => Action::UndoMove,
+            ControlAction::SelectB => Action::Redo,
             ControlAction::Start => Action::Restart,
-            ControlAction::Select => return GameResult::Continue(None),
+            ControlAction::SelectStart => {
+                renderer.show_thinking();
+                Action::Hint
+            }
+            ControlAction::Select => {
+                return GameResult::Continue(LevelExit::Suspended(level_run.suspend()));
+            }
         };
 
         let change = level_run.execute_action(action);
         change.render(&mut renderer);
 
-        if change.winning_status.is_some() {
+        if let Some(level_status) = change.winning_status {
             controller.wait_for_proceed()?;
 
-            break GameResult::Continue(change.winning_status);
+            break GameResult::Continue(LevelExit::Won(level_status));
+        }
+    }
+}
+
+// Time between moves when the replay is playing
+const REPLAY_STEP_MS: u32 = 700;
+
+pub fn replay_level<C: Controller, G: GameOutput>(
+    controller: &mut C,
+    output: &mut G,
+    level_info: &LevelInfo,
+    moves: ArrayVec<Move, MAX_OPTIMAL_MOVES>,
+) -> GameResult<()>
+where
+    G::Error: core::fmt::Debug,
+{
+    let mut replay = LevelReplay::new(level_info, moves);
+    let mut renderer = LevelRenderer::new(output, level_info.level);
+    let mut is_paused = false;
+
+    replay.render(&mut renderer);
+
+    loop {
+        let control_action = if is_paused {
+            Some(controller.wait_for_action()?)
+        } else {
+            controller.poll_action(REPLAY_STEP_MS)?
+        };
+
+        let action = match control_action {
+            // Nothing was pressed so keep playing
+            None => ReplayAction::StepForward,
+            Some(ControlAction::Move(Direction::Right)) => {
+                is_paused = true;
+                ReplayAction::StepForward
+            }
+            Some(ControlAction::Move(Direction::Left)) => {
+                is_paused = true;
+                ReplayAction::StepBack
+            }
+            // Start over if playing again after reaching the end
+            Some(ControlAction::A) if replay.is_finished() => {
+                is_paused = false;
+                ReplayAction::Restart
+            }
+            Some(ControlAction::A) => {
+                is_paused = !is_paused;
+                continue;
+            }
+            Some(ControlAction::Start) => ReplayAction::Restart,
+            Some(ControlAction::Select) => return GameResult::Continue(()),
+            _ => continue,
+        };
+
+        replay.execute_action(action).render(&mut renderer);
+
+        if replay.is_finished() {
+            is_paused = true;
         }
     }
 }
@@ -1,9 +1,9 @@
 use crate::{
+    ControlAction, Controller, GameOutput, GameResult, LevelRatingExt, PieceExt, Stars,
     assets::{pieces::SMALL_SIZE, stars::STAR_SIZE},
     display::{DISPLAY_SIZE, FONT},
-    ControlAction, Controller, GameOutput, GameResult, LevelRatingExt, PieceExt, Stars,
 };
-use arrayvec::ArrayString;
+use arrayvec::{ArrayString, ArrayVec};
 use core::fmt::Write;
 use embedded_graphics::{
     geometry::AnchorPoint,
@@ -15,12 +15,13 @@ use embedded_graphics::{
 };
 use embedded_sprites::sprite::Sprite;
 use kuboble_core::{
-    level_run::Direction as ControlDirection,
+    BufferedRenderer, LevelRating,
+    level_run::{Direction as ControlDirection, Move, SuspendedRun},
     level_select::{
-        render::LevelSelectRenderer, Action, Direction, Filter, LevelInfo, LevelSelector,
-        LevelSlotInfo,
+        Action, Direction, Filter, LevelInfo, LevelSelector, LevelSlotInfo, LevelStatus,
+        render::LevelSelectRenderer,
     },
-    BufferedRenderer, LevelRating,
+    levels::{MAX_NUM_PIECES, MAX_OPTIMAL_MOVES},
 };
 
 const LEVEL_WINDOW_SIZE: usize = 7;
@@ -176,7 +177,7 @@ where
                     + FONT.character_size.width as i32 * 9
                     + FONT.character_spacing as i32 * 8
                     + SECTION_GAP;
-                for (i, piece) in level_info.level.all_pieces().enumerate() {
+                for (i, piece) in level_info.level.goal_pieces().enumerate() {
                     Sprite::new(
                         slot_rectangle.top_left
                             + Point::new(
@@ -190,7 +191,9 @@ where
                 }
 
                 // Draw rating
-                let rating_x: i32 = pieces_x + SMALL_SIZE.width as i32 * 3 + 2 + SECTION_GAP;
+                let rating_x: i32 =
+                    pieces_x + (SMALL_SIZE.width as i32 + 1) * MAX_NUM_PIECES as i32 - 1
+                        + SECTION_GAP;
                 level_info
                     .rating
                     .stars(Point::new(
@@ -286,11 +289,18 @@ where
     }
 }
 
+pub enum Selection {
+    // Along with the run to resume, if the level was left part way through
+    Play(LevelInfo<'static>, Option<SuspendedRun>),
+    // Watch the stored optimal solution
+    Replay(LevelInfo<'static>, ArrayVec<Move, MAX_OPTIMAL_MOVES>),
+}
+
 pub fn select_level<C: Controller, G: GameOutput>(
     controller: &mut C,
     output: &mut G,
     level_selector: &mut LevelSelector<LEVEL_WINDOW_SIZE>,
-) -> GameResult<LevelInfo>
+) -> GameResult<Selection>
 where
     G::Error: core::fmt::Debug,
 {
@@ -307,9 +317,23 @@ where
             },
             ControlAction::Select => Action::ChangeActiveFilter(Direction::Next),
             ControlAction::A | ControlAction::Start => match level_selector.active_level_info() {
-                Some(level_info) => return GameResult::Continue(level_info),
+                Some(level_info) => {
+                    return GameResult::Continue(Selection::Play(
+                        level_info,
+                        level_selector.active_suspended_run(),
+                    ));
+                }
                 None => continue,
             },
+            ControlAction::B => match level_selector.active_level_status() {
+                Some(LevelStatus::Optimal(moves)) => {
+                    return GameResult::Continue(Selection::Replay(
+                        level_selector.active_level_info().unwrap(),
+                        moves,
+                    ));
+                }
+                _ => continue,
+            },
             _ => {
                 continue;
             }
@@ -12,12 +12,12 @@ use display::FONT;
 use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
 use embedded_sprites::{image::Image, sprite::Sprite};
 use kuboble_core::{
+    Level, LevelRating, Piece, Rules, Vector,
     level_run::Direction,
-    level_select::{Action, LevelProgress, LevelSelector},
-    LevelRating, Piece, Vector,
+    level_select::{Action, LevelInfo, LevelProgress, LevelSelector},
 };
-use level_run::play_level;
-use level_select::select_level;
+use level_run::{LevelExit, play_level, replay_level};
+use level_select::{Selection, select_level};
 
 pub mod display;
 mod level_run;
@@ -25,8 +25,9 @@ mod level_select;
 
 pub mod prelude {
     pub use super::{
-        display::{BufferedDisplay, DisplayTextStyle, DisplayWriter, DISPLAY_SIZE, FONT},
-        run_game, ControlAction, Controller, GameDisplay, GameIndicator, GameOutput, GameResult,
+        ControlAction, Controller, GameDisplay, GameIndicator, GameOutput, GameResult,
+        display::{BufferedDisplay, DISPLAY_SIZE, DisplayTextStyle, DisplayWriter, FONT},
+        run_game, run_levels,
     };
     pub use embedded_graphics;
 }
@@ -45,6 +46,18 @@ mod assets {
         pub const WALL: Image<Rgb565> = "assets/spaces/wall.png";
         #[include_image]
         pub const FREE: Image<Rgb565> = "assets/spaces/free.png";
+        #[include_image]
+        pub const STOP: Image<Rgb565> = "assets/spaces/stop.png";
+        #[include_image]
+        pub const ONE_WAY_UP: Image<Rgb565> = "assets/spaces/one_way_up.png";
+        #[include_image]
+        pub const ONE_WAY_DOWN: Image<Rgb565> = "assets/spaces/one_way_down.png";
+        #[include_image]
+        pub const ONE_WAY_LEFT: Image<Rgb565> = "assets/spaces/one_way_left.png";
+        #[include_image]
+        pub const ONE_WAY_RIGHT: Image<Rgb565> = "assets/spaces/one_way_right.png";
+        #[include_image]
+        pub const TELEPORT: Image<Rgb565> = "assets/spaces/teleport.png";
     }
 
     pub mod pieces {
@@ -68,6 +81,18 @@ mod assets {
         pub const BLUE_ACTIVE: Image<Rgb565> = "assets/pieces/blue_active.png";
         #[include_image]
         pub const BLUE_SMALL: Image<Rgb565> = "assets/pieces/blue_small.png";
+        #[include_image]
+        pub const PURPLE: Image<Rgb565> = "assets/pieces/purple.png";
+        #[include_image]
+        pub const PURPLE_ACTIVE: Image<Rgb565> = "assets/pieces/purple_active.png";
+        #[include_image]
+        pub const PURPLE_SMALL: Image<Rgb565> = "assets/pieces/purple_small.png";
+        #[include_image]
+        pub const GREY: Image<Rgb565> = "assets/pieces/grey.png";
+        #[include_image]
+        pub const GREY_ACTIVE: Image<Rgb565> = "assets/pieces/grey_active.png";
+        #[include_image]
+        pub const GREY_SMALL: Image<Rgb565> = "assets/pieces/grey_small.png";
 
         // Annoyingly, embedded_sprites::image::Image has no way to get the image size.
         pub static SMALL_SIZE: Size = Size::new(8, 8);
@@ -114,6 +139,8 @@ impl PieceExt for Piece {
             Piece::Green => Rgb565::CSS_FOREST_GREEN,
             Piece::Orange => Rgb565::new(29, 23, 5),
             Piece::Blue => Rgb565::BLUE,
+            Piece::Purple => Rgb565::new(27, 0, 31),
+            Piece::Grey1 | Piece::Grey2 | Piece::Grey3 => Rgb565::CSS_GRAY,
         }
     }
 
@@ -140,6 +167,20 @@ impl PieceExt for Piece {
                     assets::pieces::BLUE
                 }
             }
+            Piece::Purple => {
+                if is_active {
+                    assets::pieces::PURPLE_ACTIVE
+                } else {
+                    assets::pieces::PURPLE
+                }
+            }
+            Piece::Grey1 | Piece::Grey2 | Piece::Grey3 => {
+                if is_active {
+                    assets::pieces::GREY_ACTIVE
+                } else {
+                    assets::pieces::GREY
+                }
+            }
         }
     }
 
@@ -148,6 +189,8 @@ impl PieceExt for Piece {
             Piece::Green => assets::pieces::GREEN_SMALL,
             Piece::Orange => assets::pieces::ORANGE_SMALL,
             Piece::Blue => assets::pieces::BLUE_SMALL,
+            Piece::Purple => assets::pieces::PURPLE_SMALL,
+            Piece::Grey1 | Piece::Grey2 | Piece::Grey3 => assets::pieces::GREY_SMALL,
         }
     }
 }
@@ -247,10 +290,20 @@ pub enum ControlAction {
     B,
     Start,
     Select,
+    // B pressed while Select is held down
+    SelectB,
+    // A pressed while Select is held down
+    SelectA,
+    // Start pressed while Select is held down
+    SelectStart,
+    // Picks a piece directly, where the controls allow it
+    Piece(Piece),
 }
 
 pub trait Controller {
     fn wait_for_action(&mut self) -> GameResult<ControlAction>;
+    // Like `wait_for_action` but gives up after roughly the timeout
+    fn poll_action(&mut self, timeout_ms: u32) -> GameResult<Option<ControlAction>>;
     fn wait_for_proceed(&mut self) -> GameResult<()> {
         loop {
             match self.wait_for_action()? {
@@ -308,13 +361,58 @@ pub fn run_game<C: Controller, G: GameOutput>(
 where
     G::Error: core::fmt::Debug,
 {
+    let rules = level_progress.rules();
     let mut level_selector = LevelSelector::new(level_progress);
 
     loop {
-        let level_info = select_level(&mut controller, &mut output, &mut level_selector)?;
-
-        if let Some(level_status) = play_level(&mut controller, &mut output, &level_info)? {
-            level_selector.execute_action(Action::ActiveLevelCompleted(level_status));
+        match select_level(&mut controller, &mut output, &mut level_selector)? {
+            Selection::Play(level_info, suspended_run) => {
+                let action = match play_level(
+                    &mut controller,
+                    &mut output,
+                    &level_info,
+                    &rules,
+                    suspended_run.as_ref(),
+                )? {
+                    LevelExit::Won(level_status) => Action::ActiveLevelCompleted(level_status),
+                    LevelExit::Suspended(suspended_run) => {
+                        Action::ActiveLevelSuspended(suspended_run)
+                    }
+                };
+                level_selector.execute_action(action);
+            }
+            Selection::Replay(level_info, moves) => {
+                replay_level(&mut controller, &mut output, &level_info, moves)?
+            }
         }
     }
 }
+
+// Plays through levels that were not built into the game, such as those loaded at runtime.
+//
+// Progress is not tracked, and exiting a level moves on to the next one.
+pub fn run_levels<C: Controller, G: GameOutput>(
+    mut controller: C,
+    mut output: G,
+    levels: &[Level],
+    rules: &Rules,
+) -> GameResult<()>
+where
+    G::Error: core::fmt::Debug,
+{
+    for (index, level) in levels.iter().enumerate() {
+        play_level(
+            &mut controller,
+            &mut output,
+            &LevelInfo {
+                index,
+                rating: LevelRating::default(),
+                level,
+            },
+            rules,
+            None,
+        )?;
+    }
+
+    GameResult::Continue(())
+}